@@ -40,23 +40,31 @@ pub enum Route {
     NotFound,
 }
 
+/// The pages of the application, rendered in a router.
+#[component]
+pub fn Layout() -> Html {
+    html! {
+        <Nav />
+
+        <main>
+            <Switch<Route> render={switch} />
+        </main>
+        <footer class="footer">
+            <div class="content has-text-centered">
+                { "Powered by " }
+                <a href="https://yew.rs">{ "Yew" }</a>
+                { " using " }
+                <a href="https://bulma.io">{ "Bulma" }</a>
+            </div>
+        </footer>
+    }
+}
+
 #[component]
 pub fn App() -> Html {
     html! {
         <BrowserRouter>
-            <Nav />
-
-            <main>
-                <Switch<Route> render={switch} />
-            </main>
-            <footer class="footer">
-                <div class="content has-text-centered">
-                    { "Powered by " }
-                    <a href="https://yew.rs">{ "Yew" }</a>
-                    { " using " }
-                    <a href="https://bulma.io">{ "Bulma" }</a>
-                </div>
-            </footer>
+            <Layout />
         </BrowserRouter>
     }
 }
@@ -76,19 +84,7 @@ pub fn ServerApp(props: &ServerAppProps) -> Html {
 
     html! {
         <Router history={history}>
-            <Nav />
-
-            <main>
-                <Switch<Route> render={switch} />
-            </main>
-            <footer class="footer">
-                <div class="content has-text-centered">
-                    { "Powered by " }
-                    <a href="https://yew.rs">{ "Yew" }</a>
-                    { " using " }
-                    <a href="https://bulma.io">{ "Bulma" }</a>
-                </div>
-            </footer>
+            <Layout />
         </Router>
     }
 }
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use function_router::{Layout, Route};
+use yew_router::ssg::{RouteParams, StaticSiteGenerator};
+
+/// Pre-renders every page of the example into static HTML files.
+#[derive(Parser, Debug)]
+struct Opt {
+    /// the "dist" created by trunk directory, whose index.html the pages are rendered into.
+    #[clap(short, long)]
+    dir: PathBuf,
+    /// the directory the pages and the assets of the "dist" directory are written into.
+    #[clap(short, long, default_value = "static")]
+    out: PathBuf,
+    /// the number of posts and authors to render.
+    #[clap(short, long, default_value_t = 10)]
+    count: u32,
+}
+
+fn ids(count: u32) -> Vec<RouteParams> {
+    (0..count)
+        .map(|id| HashMap::from([("id".to_owned(), id.to_string())]))
+        .collect()
+}
+
+/// Copies the assets built by trunk into `out`, except the index.html template, which would be
+/// overwritten by the `/` page.
+async fn copy_assets(dir: &Path, out: &Path) -> io::Result<()> {
+    tokio::fs::create_dir_all(out).await?;
+
+    let mut entries = tokio::fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let to = out.join(entry.file_name());
+        if entry.file_type().await?.is_dir() {
+            Box::pin(copy_assets(&entry.path(), &to)).await?;
+        } else if entry.file_name() != "index.html" {
+            tokio::fs::copy(entry.path(), to).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let opts = Opt::parse();
+
+    tokio::fs::create_dir_all(&opts.out).await?;
+    if tokio::fs::canonicalize(&opts.out).await? == tokio::fs::canonicalize(&opts.dir).await? {
+        return Err(
+            "the pages cannot be written into the \"dist\" directory, as they would overwrite its \
+             index.html"
+                .into(),
+        );
+    }
+
+    let index_html = tokio::fs::read_to_string(opts.dir.join("index.html")).await?;
+    let count = opts.count;
+
+    copy_assets(&opts.dir, &opts.out).await?;
+
+    let pages = StaticSiteGenerator::<Route, Layout>::new(opts.out)
+        .template(index_html)
+        .params(move |pattern| match pattern {
+            "/posts/{id}" | "/authors/{id}" => ids(count),
+            _ => Vec::new(),
+        })
+        .generate()
+        .await?;
+
+    for page in pages {
+        println!("{} -> {}", page.path, page.file.display());
+    }
+
+    Ok(())
+}
@@ -3,10 +3,12 @@ use quote::quote;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Fields, Ident, LitStr, Variant};
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, LitStr, Meta, Type, Variant};
 
 const AT_ATTR_IDENT: &str = "at";
 const NOT_FOUND_ATTR_IDENT: &str = "not_found";
+const NESTED_ATTR_IDENT: &str = "nested";
+const LOADER_ATTR_IDENT: &str = "loader";
 
 /// Extract parameter names from a matchit-style route pattern.
 /// E.g. `"/posts/{id}"` → `["id"]`, `"/files/{*path}"` → `["path"]`.
@@ -30,13 +32,21 @@ fn extract_route_params(route: &str) -> Vec<String> {
 pub struct Routable {
     ident: Ident,
     ats: Vec<LitStr>,
+    /// The type of the nested route of each variant, if it is nested.
+    ///
+    /// The `at` of a nested variant is the prefix of its nested routes.
+    nested: Vec<Option<Type>>,
     variants: Punctuated<Variant, syn::token::Comma>,
     not_found_route: Option<Ident>,
+    /// Whether the routes have a `RouteLoader`.
+    loader: bool,
 }
 
 impl Parse for Routable {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let DeriveInput { ident, data, .. } = input.parse()?;
+        let DeriveInput {
+            ident, data, attrs, ..
+        } = input.parse()?;
 
         let data = match data {
             Data::Enum(data) => data,
@@ -54,32 +64,88 @@ impl Parse for Routable {
             }
         };
 
-        let (not_found_route, ats) = parse_variants_attributes(&data.variants)?;
+        let (not_found_route, ats, nested) = parse_variants_attributes(&data.variants)?;
+        let loader = parse_loader_attribute(&attrs, &data.variants)?;
 
         Ok(Self {
             ident,
             variants: data.variants,
             ats,
+            nested,
             not_found_route,
+            loader,
         })
     }
 }
 
+/// Returns whether the enum is marked with `#[loader]`.
+fn parse_loader_attribute(
+    attrs: &[Attribute],
+    variants: &Punctuated<Variant, syn::token::Comma>,
+) -> syn::Result<bool> {
+    if let Some(attr) = variants
+        .iter()
+        .flat_map(|variant| &variant.attrs)
+        .find(|attr| attr.path().is_ident(LOADER_ATTR_IDENT))
+    {
+        return Err(syn::Error::new_spanned(
+            attr,
+            format!("the {LOADER_ATTR_IDENT} attribute must be placed on the enum"),
+        ));
+    }
+
+    match attrs
+        .iter()
+        .find(|attr| attr.path().is_ident(LOADER_ATTR_IDENT))
+    {
+        Some(attr) if matches!(attr.meta, Meta::Path(_)) => Ok(true),
+        Some(attr) => Err(syn::Error::new_spanned(
+            attr,
+            format!(
+                "the {LOADER_ATTR_IDENT} attribute takes no arguments, the data is loaded by the \
+                 implementation of `RouteLoader`"
+            ),
+        )),
+        None => Ok(false),
+    }
+}
+
+/// Returns the type of the nested route of a `#[nested]` variant.
+fn parse_nested_variant(variant: &Variant) -> syn::Result<Option<Type>> {
+    let nested_attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident(NESTED_ATTR_IDENT));
+
+    match (&variant.fields, nested_attr) {
+        (Fields::Unnamed(fields), Some(_)) if fields.unnamed.len() == 1 => {
+            Ok(Some(fields.unnamed[0].ty.clone()))
+        }
+        (_, Some(attr)) => Err(syn::Error::new_spanned(
+            attr,
+            "nested routes must have a single unnamed field holding the nested route",
+        )),
+        (Fields::Unnamed(field), None) => Err(syn::Error::new(
+            field.span(),
+            "only named fields are supported",
+        )),
+        (_, None) => Ok(None),
+    }
+}
+
+type VariantsAttributes = (Option<Ident>, Vec<LitStr>, Vec<Option<Type>>);
+
 fn parse_variants_attributes(
     variants: &Punctuated<Variant, syn::token::Comma>,
-) -> syn::Result<(Option<Ident>, Vec<LitStr>)> {
+) -> syn::Result<VariantsAttributes> {
     let mut not_founds = vec![];
     let mut ats: Vec<LitStr> = vec![];
+    let mut nested = vec![];
 
     let mut not_found_attrs = vec![];
 
     for variant in variants.iter() {
-        if let Fields::Unnamed(ref field) = variant.fields {
-            return Err(syn::Error::new(
-                field.span(),
-                "only named fields are supported",
-            ));
-        }
+        let nested_route = parse_nested_variant(variant)?;
 
         let attrs = &variant.attrs;
         let at_attrs = attrs
@@ -104,7 +170,13 @@ fn parse_variants_attributes(
         };
 
         let lit = attr.parse_args::<LitStr>()?;
-        let val = lit.value();
+        let mut val = lit.value();
+        if nested_route.is_some() {
+            // `/settings`, `/settings/` and `/settings/*` are the same prefix.
+            if let Some(prefix) = val.strip_suffix("/*") {
+                val = format!("{prefix}/");
+            }
+        }
 
         if val.find('#').is_some() {
             return Err(syn::Error::new_spanned(
@@ -144,7 +216,12 @@ fn parse_variants_attributes(
         }
 
         let route_params = extract_route_params(&val);
-        if !route_params.is_empty() {
+        if nested_route.is_some() && !route_params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "the path of nested routes cannot have parameters",
+            ));
+        } else if !route_params.is_empty() {
             let field_names: std::collections::HashSet<String> = match &variant.fields {
                 Fields::Named(fields) => fields
                     .named
@@ -169,14 +246,30 @@ fn parse_variants_attributes(
             }
         }
 
-        ats.push(lit);
-
         for attr in attrs.iter() {
             if attr.path().is_ident(NOT_FOUND_ATTR_IDENT) {
+                if nested_route.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        format!("nested routes cannot be {NOT_FOUND_ATTR_IDENT}"),
+                    ));
+                }
+
                 not_found_attrs.push(attr);
                 not_founds.push(variant.ident.clone())
             }
         }
+
+        match nested_route {
+            Some(m) => {
+                ats.push(LitStr::new(val.trim_end_matches('/'), lit.span()));
+                nested.push(Some(m));
+            }
+            None => {
+                ats.push(lit);
+                nested.push(None);
+            }
+        }
     }
 
     if not_founds.len() > 1 {
@@ -186,12 +279,24 @@ fn parse_variants_attributes(
         ));
     }
 
-    Ok((not_founds.into_iter().next(), ats))
+    Ok((not_founds.into_iter().next(), ats, nested))
 }
 
 impl Routable {
+    /// Returns the variants with their `at` and the type of their nested route.
+    fn nested_variants(&self) -> impl Iterator<Item = (&Ident, &LitStr, &Type)> {
+        self.variants
+            .iter()
+            .zip(self.ats.iter().zip(self.nested.iter()))
+            .filter_map(|(variant, (at, nested))| Some((&variant.ident, at, nested.as_ref()?)))
+    }
+
     fn build_from_path(&self) -> TokenStream {
-        let from_path_matches = self.variants.iter().enumerate().map(|(i, variant)| {
+        let from_path_matches = self.variants.iter().enumerate().filter_map(|(i, variant)| {
+            if self.nested[i].is_some() {
+                return None;
+            }
+
             let ident = &variant.ident;
             let right = match &variant.fields {
                 Fields::Unit => quote! { Self::#ident },
@@ -207,21 +312,112 @@ impl Routable {
                         param
                     },)* } }
                 }
-                Fields::Unnamed(_) => unreachable!(), // already checked
+                Fields::Unnamed(_) => unreachable!(), // nested routes are matched separately
             };
 
             let left = self.ats.get(i).unwrap();
-            quote! {
+            Some(quote! {
                 #left => ::std::option::Option::Some(#right)
+            })
+        });
+
+        // The paths of nested routes are prefixed with the path of their variant.
+        let from_nested_paths = self.nested_variants().map(|(ident, prefix, ty)| {
+            quote! {
+                if let ::std::option::Option::Some(path) = ::yew_router::__macro::strip_nested_prefix(path, #prefix) {
+                    if let ::std::option::Option::Some(route) = <#ty as ::yew_router::Routable>::from_path(path, params) {
+                        return ::std::option::Option::Some(Self::#ident(route));
+                    }
+                }
             }
         });
 
         quote! {
             fn from_path(path: &str, params: &::std::collections::HashMap<&str, &str>) -> ::std::option::Option<Self> {
-                match path {
-                    #(#from_path_matches),*,
+                #[allow(clippy::match_single_binding)]
+                let route = match path {
+                    #(#from_path_matches,)*
                     _ => ::std::option::Option::None,
+                };
+                if route.is_some() {
+                    return route;
+                }
+                #(#from_nested_paths)*
+
+                ::std::option::Option::None
+            }
+        }
+    }
+
+    fn build_routes(&self) -> TokenStream {
+        let ats = &self.ats;
+        if self.nested.iter().all(Option::is_none) {
+            return quote! {
+                fn routes() -> ::std::vec::Vec<&'static str> {
+                    ::std::vec![#(#ats),*]
                 }
+            };
+        }
+
+        let routes = self
+            .ats
+            .iter()
+            .zip(self.nested.iter())
+            .map(|(at, nested)| match nested {
+                Some(ty) => quote! {
+                    routes.extend(::yew_router::__macro::nested_routes::<#ty>(#at));
+                },
+                None => quote! {
+                    routes.push(#at);
+                },
+            });
+
+        quote! {
+            fn routes() -> ::std::vec::Vec<&'static str> {
+                static ROUTES: ::std::sync::OnceLock<::std::vec::Vec<&'static str>> = ::std::sync::OnceLock::new();
+                ROUTES
+                    .get_or_init(|| {
+                        let mut routes = ::std::vec::Vec::new();
+                        #(#routes)*
+                        routes
+                    })
+                    .clone()
+            }
+        }
+    }
+
+    fn build_nested_path(&self) -> TokenStream {
+        let nested_paths = self
+            .nested_variants()
+            .map(|(ident, ..)| {
+                quote! {
+                    Self::#ident(route) => ::std::option::Option::Some(::yew_router::Routable::to_path(route))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if nested_paths.is_empty() {
+            return TokenStream::new();
+        }
+
+        quote! {
+            fn nested_path(&self) -> ::std::option::Option<::std::string::String> {
+                match self {
+                    #(#nested_paths,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    }
+
+    fn build_with_loader(&self) -> TokenStream {
+        if !self.loader {
+            return TokenStream::new();
+        }
+
+        quote! {
+            fn __with_loader(&self, html: ::yew_router::__macro::Html) -> ::yew_router::__macro::Html {
+                ::yew_router::__macro::provide_loader(::std::clone::Clone::clone(self), html)
             }
         }
     }
@@ -231,6 +427,12 @@ impl Routable {
             let ident = &variant.ident;
             let mut right = self.ats.get(i).unwrap().value();
 
+            if self.nested[i].is_some() {
+                return quote! {
+                    Self::#ident(route) => ::yew_router::__macro::join_nested_path(#right, &::yew_router::Routable::to_path(route))
+                };
+            }
+
             match &variant.fields {
                 Fields::Unit => quote! { Self::#ident => ::std::string::ToString::to_string(#right) },
                 Fields::Named(field) => {
@@ -267,7 +469,7 @@ impl Routable {
                         Self::#ident { #(#fields),* } => ::std::format!(#right, #(#field_encodings),*)
                     }
                 }
-                Fields::Unnamed(_) => unreachable!(), // already checked
+                Fields::Unnamed(_) => unreachable!(), // only nested routes are unnamed
             }
         });
 
@@ -283,7 +485,6 @@ impl Routable {
 
 pub fn routable_derive_impl(input: Routable) -> TokenStream {
     let Routable {
-        ats,
         not_found_route,
         ident,
         ..
@@ -291,6 +492,9 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
 
     let from_path = input.build_from_path();
     let to_path = input.build_to_path();
+    let routes = input.build_routes();
+    let nested_path = input.build_nested_path();
+    let with_loader = input.build_with_loader();
 
     let maybe_not_found_route = match not_found_route {
         Some(route) => quote! { ::std::option::Option::Some(Self::#route) },
@@ -315,10 +519,9 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
         impl ::yew_router::Routable for #ident {
             #from_path
             #to_path
-
-            fn routes() -> ::std::vec::Vec<&'static str> {
-                ::std::vec![#(#ats),*]
-            }
+            #routes
+            #nested_path
+            #with_loader
 
             fn not_found_route() -> ::std::option::Option<Self> {
                 #maybe_not_found_route
@@ -0,0 +1,15 @@
+#[derive(Clone, PartialEq, yew_router::Routable)]
+#[loader(load)]
+enum Routes {
+    #[at("/")]
+    Home,
+}
+
+#[derive(Clone, PartialEq, yew_router::Routable)]
+enum RoutesTwo {
+    #[loader]
+    #[at("/")]
+    Home,
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+#[derive(Clone, PartialEq, yew_router::Routable)]
+enum Nested {
+    #[at("/")]
+    Home,
+}
+
+#[derive(yew_router::Routable)]
+enum Routes {
+    #[nested]
+    #[at("/one")]
+    One { nested: Nested },
+}
+
+#[derive(yew_router::Routable)]
+enum RoutesTwo {
+    #[nested]
+    #[at("/{id}")]
+    One(Nested),
+}
+
+#[derive(yew_router::Routable)]
+enum RoutesThree {
+    #[nested]
+    #[not_found]
+    #[at("/one")]
+    One(Nested),
+}
+
+fn main() {}
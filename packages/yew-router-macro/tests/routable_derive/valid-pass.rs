@@ -21,4 +21,16 @@ enum MoreRoutes {
     CatchAll { all: ::std::string::String },
 }
 
+#[derive(Debug, PartialEq, Clone, ::yew_router::Routable)]
+enum NestedRoutes {
+    #[at("/")]
+    Home,
+    #[nested]
+    #[at("/routes/*")]
+    Routes(Routes),
+    #[nested]
+    #[at("/more")]
+    More(MoreRoutes),
+}
+
 fn main() {}
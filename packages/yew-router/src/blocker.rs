@@ -0,0 +1,372 @@
+//! Blocking of navigations, such as to ask for confirmation before unsaved changes are lost.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use yew::Callback;
+
+use crate::history::{AnyHistory, History, Location, MemoryHistory};
+use crate::query::Raw;
+
+pub(crate) type ShouldBlock = Rc<RefCell<Box<dyn Fn(&Location, &Location) -> bool>>>;
+
+type Proceed = Rc<RefCell<Option<Box<dyn FnOnce()>>>>;
+
+/// How a navigation changes the history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NavigationKind {
+    Push,
+    Replace,
+    /// Going the given number of entries through the history.
+    Go(isize),
+}
+
+struct Blocker {
+    id: usize,
+    should_block: ShouldBlock,
+    set_pending: Callback<Option<PendingNavigation>>,
+}
+
+#[derive(Default)]
+struct BlockersInner {
+    next_id: usize,
+    blockers: Vec<Blocker>,
+    // The entries of the history as seen by the router, used to find how far a navigation that
+    // did not go through the navigator, such as the back button, has moved in the history.
+    entries: Vec<Location>,
+    index: usize,
+    // The navigation the navigator is making, which has already been checked.
+    navigating: Option<NavigationKind>,
+}
+
+impl BlockersInner {
+    /// Records that the history has moved `delta` entries to `location`, which is at `position`
+    /// of the entries if it has been recorded before.
+    fn move_to(&mut self, location: Location, position: Option<usize>, delta: isize) {
+        match position {
+            Some(position) => {
+                self.entries[position] = location;
+                self.index = position;
+            }
+            // The entry right before the first recorded one, such as the entry before a reload.
+            None if delta == -1 && self.index == 0 => {
+                self.entries.insert(0, location);
+            }
+            // A new entry has been pushed, or the entry right after the last recorded one has
+            // been reached.
+            None if delta == 1 => {
+                self.entries.truncate(self.index + 1);
+                self.entries.push(location);
+                self.index += 1;
+            }
+            // How the entry relates to the recorded ones is unknown.
+            None => {
+                self.entries = vec![location];
+                self.index = 0;
+            }
+        }
+    }
+}
+
+/// The blockers registered with [`use_blocker`](crate::hooks::use_blocker) under a router.
+#[derive(Clone, Default)]
+pub(crate) struct Blockers {
+    inner: Rc<RefCell<BlockersInner>>,
+}
+
+impl PartialEq for Blockers {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &rhs.inner)
+    }
+}
+
+impl fmt::Debug for Blockers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blockers")
+            .field("len", &self.inner.borrow().blockers.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Blockers {
+    pub fn register(
+        &self,
+        should_block: ShouldBlock,
+        set_pending: Callback<Option<PendingNavigation>>,
+    ) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.blockers.push(Blocker {
+            id,
+            should_block,
+            set_pending,
+        });
+
+        id
+    }
+
+    pub fn unregister(&self, id: usize) {
+        self.inner.borrow_mut().blockers.retain(|m| m.id != id);
+    }
+
+    /// Returns `true` if any blocker is registered.
+    pub fn is_blocking(&self) -> bool {
+        !self.inner.borrow().blockers.is_empty()
+    }
+
+    /// Forgets the entries of the history, which is now at `location`.
+    pub fn reset(&self, location: Location) {
+        let mut inner = self.inner.borrow_mut();
+        inner.entries = vec![location];
+        inner.index = 0;
+        inner.navigating = None;
+    }
+
+    /// Returns the location `delta` entries away from the current entry, if it is known.
+    pub fn entry(&self, delta: isize) -> Option<Location> {
+        let inner = self.inner.borrow();
+        let index = inner.index.checked_add_signed(delta)?;
+
+        inner.entries.get(index).cloned()
+    }
+
+    /// Makes a navigation to `to` with `navigate`, unless it is blocked.
+    ///
+    /// A blocked navigation is handed to the first blocker blocking it, which can proceed with it
+    /// later.
+    pub fn navigate(
+        &self,
+        kind: NavigationKind,
+        to: Option<Location>,
+        navigate: impl 'static + FnOnce(),
+    ) {
+        let blocked = to.and_then(|to| Some((self.find_blocker(&to)?, to)));
+
+        match blocked {
+            Some(((from, set_pending), to)) => {
+                let blockers = self.clone();
+                let proceed: Box<dyn FnOnce()> = Box::new(move || blockers.run(kind, navigate));
+                set_pending.emit(Some(PendingNavigation {
+                    from,
+                    to,
+                    proceed: Rc::new(RefCell::new(Some(proceed))),
+                    set_pending: set_pending.clone(),
+                }));
+            }
+            None => self.run(kind, navigate),
+        }
+    }
+
+    /// Makes a navigation of `kind` with `navigate`, which is not checked with the blockers when
+    /// it is observed.
+    fn run(&self, kind: NavigationKind, navigate: impl FnOnce()) {
+        self.inner.borrow_mut().navigating = Some(kind);
+        navigate();
+
+        // Pushes and replacements are observed while they are made, if they change the history at
+        // all. Going through the history is only observed once the browser has moved to the entry,
+        // and is never observed if there is no entry to move to.
+        if !matches!(kind, NavigationKind::Go(_)) {
+            self.inner.borrow_mut().navigating = None;
+        }
+    }
+
+    /// Records that `history` has changed to `location`.
+    ///
+    /// Changes that have not been made by the navigator are checked with the blockers, and a
+    /// blocked change is reverted. Returns `false` if the change has been reverted.
+    pub fn observe(&self, location: Location, history: &AnyHistory) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        let kind = inner.navigating.take();
+        let index = inner.index;
+
+        let Some(current) = inner.entries.get(index) else {
+            drop(inner);
+            self.reset(location);
+            return true;
+        };
+
+        if is_same_entry(current, &location) {
+            inner.entries[index] = location;
+            return true;
+        }
+
+        let known = inner
+            .entries
+            .iter()
+            .position(|m| is_same_entry(m, &location));
+        // An entry that has not been recorded is assumed to be the entry before the first recorded
+        // one, such as after a reload, or otherwise a new entry pushed without the navigator.
+        let unknown_delta = if index == 0 { -1 } else { 1 };
+        let (kind, position, delta) = match kind {
+            Some(NavigationKind::Push) => (kind, None, 1),
+            Some(NavigationKind::Replace) => (kind, Some(index), 0),
+            Some(NavigationKind::Go(delta)) => {
+                let target = index
+                    .checked_add_signed(delta)
+                    .filter(|m| *m < inner.entries.len());
+
+                // Unless the history has moved to the entry the navigator went to, the navigator
+                // has not moved it, and another navigation has.
+                match known {
+                    Some(m) if known != target => (None, known, m as isize - index as isize),
+                    None if target.is_some() => (None, None, unknown_delta),
+                    _ => (kind, known, delta),
+                }
+            }
+            None => match known {
+                Some(m) => (None, known, m as isize - index as isize),
+                None => (None, None, unknown_delta),
+            },
+        };
+
+        if kind.is_none() {
+            drop(inner);
+
+            if let Some((from, set_pending)) = self.find_blocker(&location) {
+                // A pushed entry has replaced the entries after the current one.
+                if position.is_none() && delta > 0 {
+                    self.inner.borrow_mut().entries.truncate(index + 1);
+                }
+                history.go(-delta);
+
+                let history = history.clone();
+                let blockers = self.clone();
+                let proceed: Box<dyn FnOnce()> = Box::new(move || {
+                    blockers.inner.borrow_mut().navigating = Some(NavigationKind::Go(delta));
+                    history.go(delta);
+                });
+                set_pending.emit(Some(PendingNavigation {
+                    from,
+                    to: location,
+                    proceed: Rc::new(RefCell::new(Some(proceed))),
+                    set_pending: set_pending.clone(),
+                }));
+
+                return false;
+            }
+
+            inner = self.inner.borrow_mut();
+        }
+
+        inner.move_to(location, position, delta);
+
+        true
+    }
+
+    /// Returns the current location and the first blocker blocking the navigation to `to`.
+    fn find_blocker(
+        &self,
+        to: &Location,
+    ) -> Option<(Location, Callback<Option<PendingNavigation>>)> {
+        let (from, blockers) = {
+            let inner = self.inner.borrow();
+            let from = inner.entries.get(inner.index)?.clone();
+            let blockers = inner
+                .blockers
+                .iter()
+                .map(|m| (m.should_block.clone(), m.set_pending.clone()))
+                .collect::<Vec<_>>();
+
+            (from, blockers)
+        };
+
+        blockers
+            .into_iter()
+            .find(|(should_block, _)| (should_block.borrow())(&from, to))
+            .map(|(_, set_pending)| (from, set_pending))
+    }
+}
+
+/// Returns `true` if `lhs` and `rhs` are the same entry of a history.
+fn is_same_entry(lhs: &Location, rhs: &Location) -> bool {
+    match (lhs.id(), rhs.id()) {
+        (Some(lhs), Some(rhs)) => lhs == rhs,
+        // Entries that have not been created by the history, such as the entry the page has been
+        // loaded with, have no id.
+        (None, None) => {
+            lhs.path() == rhs.path()
+                && lhs.query_str() == rhs.query_str()
+                && lhs.hash() == rhs.hash()
+        }
+        _ => false,
+    }
+}
+
+/// Creates the location a navigation to `path` with `query` leads to.
+///
+/// The hash fragment is left out, as a [`MemoryHistory`] cannot have one.
+pub(crate) fn next_location(path: &str, query: Option<&str>) -> Location {
+    let without_hash = |m: &str| m.split_once('#').map_or(m, |m| m.0).to_owned();
+    let path = without_hash(path);
+    let query = query.map(without_hash);
+
+    let history = MemoryHistory::new();
+    match query {
+        Some(query) => history
+            .push_with_query(path, Raw(query.as_str()))
+            .unwrap_or_else(|never| match never {}),
+        None => history.push(path),
+    }
+
+    history.location()
+}
+
+/// A navigation blocked by [`use_blocker`](crate::hooks::use_blocker).
+///
+/// The navigation is made once [`proceed`](Self::proceed) is called, and cancelled by
+/// [`reset`](Self::reset).
+#[derive(Clone)]
+pub struct PendingNavigation {
+    from: Location,
+    to: Location,
+    proceed: Proceed,
+    set_pending: Callback<Option<PendingNavigation>>,
+}
+
+impl PendingNavigation {
+    /// Returns the location the navigation has been blocked at.
+    pub fn from(&self) -> &Location {
+        &self.from
+    }
+
+    /// Returns the location the navigation leads to.
+    ///
+    /// The state of the location is not available.
+    pub fn to(&self) -> &Location {
+        &self.to
+    }
+
+    /// Makes the navigation without checking it with the blockers again.
+    pub fn proceed(&self) {
+        self.set_pending.emit(None);
+
+        let proceed = self.proceed.borrow_mut().take();
+        if let Some(proceed) = proceed {
+            proceed();
+        }
+    }
+
+    /// Cancels the navigation.
+    pub fn reset(&self) {
+        self.set_pending.emit(None);
+        self.proceed.borrow_mut().take();
+    }
+}
+
+impl PartialEq for PendingNavigation {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.proceed, &rhs.proceed)
+    }
+}
+
+impl fmt::Debug for PendingNavigation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PendingNavigation")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .finish_non_exhaustive()
+    }
+}
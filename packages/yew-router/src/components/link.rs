@@ -54,53 +54,8 @@ where
     } = props.clone();
 
     let navigator = use_navigator().expect_throw("failed to get navigator");
-
-    let onclick = {
-        let navigator = navigator.clone();
-        let to = to.clone();
-        let query = query.clone();
-        let state = state.clone();
-
-        Callback::from(move |e: MouseEvent| {
-            if e.meta_key() || e.ctrl_key() || e.shift_key() || e.alt_key() {
-                return;
-            }
-            e.prevent_default();
-            match (&state, &query) {
-                (None, None) => {
-                    navigator.push(&to);
-                }
-                (Some(state), None) => {
-                    navigator.push_with_state(&to, state.clone());
-                }
-                (None, Some(query)) => {
-                    navigator
-                        .push_with_query(&to, query)
-                        .expect_throw("failed push history with query");
-                }
-                (Some(state), Some(query)) => {
-                    navigator
-                        .push_with_query_and_state(&to, query, state.clone())
-                        .expect_throw("failed push history with query and state");
-                }
-            }
-        })
-    };
-
-    let href = {
-        let route_s = to.to_path();
-        let pathname = navigator.prefix_basename(&route_s);
-        let mut path = query
-            .and_then(|query| serde_urlencoded::to_string(query).ok())
-            .and_then(|query| utils::compose_path(&pathname, &query))
-            .unwrap_or_else(|| pathname.into_owned());
-
-        if navigator.kind() == NavigatorKind::Hash {
-            path.insert(0, '#');
-        }
-
-        AttrValue::from(path)
-    };
+    let onclick = link_onclick(&navigator, &to, query.clone(), state);
+    let href = link_href(&navigator, &to, query);
 
     html! {
         <a class={classes}
@@ -113,3 +68,64 @@ where
         </a>
     }
 }
+
+/// Returns the callback pushing `to` when a link to it is clicked.
+pub(crate) fn link_onclick<R, Q, S>(
+    navigator: &Navigator,
+    to: &R,
+    query: Option<Q>,
+    state: Option<S>,
+) -> Callback<MouseEvent>
+where
+    R: Routable + 'static,
+    Q: Serialize + 'static,
+    S: Clone + 'static,
+{
+    let navigator = navigator.clone();
+    let to = to.clone();
+
+    Callback::from(move |e: MouseEvent| {
+        if e.meta_key() || e.ctrl_key() || e.shift_key() || e.alt_key() {
+            return;
+        }
+        e.prevent_default();
+        match (&state, &query) {
+            (None, None) => {
+                navigator.push(&to);
+            }
+            (Some(state), None) => {
+                navigator.push_with_state(&to, state.clone());
+            }
+            (None, Some(query)) => {
+                navigator
+                    .push_with_query(&to, query)
+                    .expect_throw("failed push history with query");
+            }
+            (Some(state), Some(query)) => {
+                navigator
+                    .push_with_query_and_state(&to, query, state.clone())
+                    .expect_throw("failed push history with query and state");
+            }
+        }
+    })
+}
+
+/// Returns the `href` of a link to `to`, prefixed with the basename of the router.
+pub(crate) fn link_href<R, Q>(navigator: &Navigator, to: &R, query: Option<Q>) -> AttrValue
+where
+    R: Routable,
+    Q: Serialize,
+{
+    let route_s = to.to_path();
+    let pathname = navigator.prefix_basename(&route_s);
+    let mut path = query
+        .and_then(|query| serde_urlencoded::to_string(query).ok())
+        .and_then(|query| utils::compose_path(&pathname, &query))
+        .unwrap_or_else(|| pathname.into_owned());
+
+    if navigator.kind() == NavigatorKind::Hash {
+        path.insert(0, '#');
+    }
+
+    AttrValue::from(path)
+}
@@ -1,6 +1,11 @@
 //! Components to interface with [Router][crate::Router].
 
 mod link;
+mod nav_link;
+mod outlet;
 mod redirect;
 pub use link::*;
+pub use nav_link::*;
+pub(crate) use outlet::OutletContext;
+pub use outlet::*;
 pub use redirect::*;
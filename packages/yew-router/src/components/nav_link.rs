@@ -0,0 +1,121 @@
+use serde::Serialize;
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+
+use super::link::{link_href, link_onclick};
+use crate::Routable;
+use crate::prelude::*;
+
+/// How the current location is matched against the target of a [`NavLink`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NavLinkMatch {
+    /// The link is active when the path of the location is the path of the target.
+    #[default]
+    Exact,
+    /// The link is active when the path of the location is the path of the target or one of
+    /// its sub-paths, e.g. `/settings/profile` for `/settings`.
+    Prefix,
+}
+
+impl NavLinkMatch {
+    /// Returns `true` if `path` matches `target`.
+    fn is_active(self, path: &str, target: &str) -> bool {
+        match self {
+            Self::Exact => path == target,
+            Self::Prefix => match path.strip_prefix(target) {
+                Some(rest) => rest.is_empty() || target.ends_with('/') || rest.starts_with('/'),
+                None => false,
+            },
+        }
+    }
+}
+
+fn default_active_class() -> Classes {
+    classes!("active")
+}
+
+/// Props for [`NavLink`]
+#[derive(Properties, Clone, PartialEq)]
+pub struct NavLinkProps<R, Q = (), S = ()>
+where
+    R: Routable,
+    Q: Clone + PartialEq + Serialize,
+    S: Clone + PartialEq,
+{
+    /// CSS classes to add to the anchor element (optional).
+    #[prop_or_default]
+    pub classes: Classes,
+    /// CSS classes to add to the anchor element when the link is active.
+    ///
+    /// Defaults to `active`.
+    #[prop_or_else(default_active_class)]
+    pub active_class: Classes,
+    /// How the current location is matched against the route.
+    #[prop_or_default]
+    pub match_mode: NavLinkMatch,
+    /// Route that will be pushed when the anchor is clicked.
+    pub to: R,
+    /// Route query data
+    #[prop_or_default]
+    pub query: Option<Q>,
+    /// Route state data
+    #[prop_or_default]
+    pub state: Option<S>,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// [`NodeRef`](yew::html::NodeRef) for the `<a>` element.
+    #[prop_or_default]
+    pub anchor_ref: NodeRef,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// A [`Link`](super::Link) that is highlighted when the current location matches its route.
+///
+/// When the path of the current location matches the path of [`to`](NavLinkProps::to), as set
+/// by [`match_mode`](NavLinkProps::match_mode), the anchor gets the
+/// [`active_class`](NavLinkProps::active_class) and `aria-current="page"`. The path is
+/// compared with the basename of the router, and the query of the location is ignored.
+#[component]
+pub fn NavLink<R, Q = (), S = ()>(props: &NavLinkProps<R, Q, S>) -> Html
+where
+    R: Routable + 'static,
+    Q: Clone + PartialEq + Serialize + 'static,
+    S: Clone + PartialEq + 'static,
+{
+    let NavLinkProps {
+        mut classes,
+        active_class,
+        match_mode,
+        to,
+        query,
+        state,
+        disabled,
+        anchor_ref,
+        children,
+    } = props.clone();
+
+    let navigator = use_navigator().expect_throw("failed to get navigator");
+    let location = use_location().expect_throw("failed to get location");
+
+    let target = navigator.prefix_basename(&to.to_path()).into_owned();
+    let active = match_mode.is_active(location.path(), &target);
+    if active {
+        classes.push(active_class);
+    }
+
+    let onclick = link_onclick(&navigator, &to, query.clone(), state);
+    let href = link_href(&navigator, &to, query);
+
+    html! {
+        <a class={classes}
+            {href}
+            {onclick}
+            {disabled}
+            aria-current={active.then_some("page")}
+            ref={anchor_ref}
+        >
+            { children }
+        </a>
+    }
+}
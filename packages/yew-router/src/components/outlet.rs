@@ -0,0 +1,68 @@
+use yew::prelude::*;
+
+use crate::Routable;
+use crate::switch::Switch;
+
+/// The path of the nested route matched by the closest [`Switch`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct OutletContext {
+    pub path: String,
+}
+
+/// Props for [`Outlet`]
+#[derive(Properties, PartialEq, Clone)]
+pub struct OutletProps<R>
+where
+    R: Routable,
+{
+    /// Callback which returns [`Html`] to be rendered for the nested route.
+    pub render: Callback<R, Html>,
+}
+
+/// Renders the nested route of a `#[nested]` variant.
+///
+/// The [`Switch`] rendering a `#[nested]` variant provides the path of its nested route, relative
+/// to the prefix of the variant. The `Outlet` matches this path against `R` like a [`Switch`]
+/// matches the location, so layouts can render the shared parts of their pages around it:
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use yew_router::prelude::*;
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum SettingsRoute {
+///     #[at("/")]
+///     Profile,
+///     #[at("/security")]
+///     Security,
+/// }
+///
+/// #[component]
+/// fn Settings() -> Html {
+///     html! {
+///         <section>
+///             <h1>{ "Settings" }</h1>
+///             <Outlet<SettingsRoute> render={|route| match route {
+///                 SettingsRoute::Profile => html! { "Profile" },
+///                 SettingsRoute::Security => html! { "Security" },
+///             }} />
+///         </section>
+///     }
+/// }
+/// ```
+///
+/// Nothing is rendered outside of a `#[nested]` variant.
+#[component]
+pub fn Outlet<R>(props: &OutletProps<R>) -> Html
+where
+    R: Routable + 'static,
+{
+    match use_context::<OutletContext>() {
+        Some(ctx) => html! {
+            <Switch<R> render={props.render.clone()} pathname={ctx.path} />
+        },
+        None => {
+            tracing::warn!("an outlet is rendered outside of a nested route");
+            Html::default()
+        }
+    }
+}
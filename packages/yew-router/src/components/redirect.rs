@@ -3,28 +3,85 @@ use yew::prelude::*;
 
 use crate::Routable;
 use crate::hooks::use_navigator;
+use crate::navigator::Navigator;
 
 /// Props for [`Redirect`]
 #[derive(Properties, Clone, PartialEq, Eq)]
 pub struct RedirectProps<R: Routable> {
     /// Route that will be pushed when the component is rendered.
     pub to: R,
+    /// The status code of the response when the component is rendered on the server.
+    ///
+    /// Defaults to `307 Temporary Redirect`.
+    #[prop_or(307)]
+    pub status: u16,
+    /// Whether the current entry of the history is replaced instead of a new one being pushed.
+    #[prop_or_default]
+    pub replace: bool,
 }
 
 /// A component that will redirect to specified route when rendered.
+///
+/// During server-side rendering, the redirect is signalled to the renderer instead: the status
+/// of the response is set to [`status`](RedirectProps::status) and its `Location` header to
+/// the route, which are returned by
+/// [`ServerRenderer::render_response`](yew::ServerRenderer::render_response).
 #[component(Redirect)]
 pub fn redirect<R>(props: &RedirectProps<R>) -> Html
 where
     R: Routable + 'static,
 {
-    let history = use_navigator().expect_throw("failed to read history.");
+    let replace = props.replace;
+    use_redirect(props.to.clone(), props.status, move |navigator, to| {
+        if replace {
+            navigator.replace(to);
+        } else {
+            navigator.push(to);
+        }
+    });
 
-    let target_route = props.to.clone();
-    use_effect(move || {
-        history.push(&target_route);
+    Html::default()
+}
 
-        || {}
+/// Props for [`GuardRedirect`]
+#[derive(Properties, PartialEq)]
+pub(crate) struct GuardRedirectProps<R: Routable> {
+    pub to: R,
+}
+
+/// Redirects to the route a [`Guard`](crate::switch::Guard) has redirected to.
+///
+/// The current entry is replaced without checking the navigation with the blockers, which are
+/// not meant to keep users on a route they are not allowed to see.
+#[component]
+pub(crate) fn GuardRedirect<R>(props: &GuardRedirectProps<R>) -> Html
+where
+    R: Routable + 'static,
+{
+    use_redirect(props.to.clone(), 307, |navigator, to| {
+        navigator.replace_unblocked(to)
     });
 
     Html::default()
 }
+
+/// Signals the redirect to `to` to the server renderer, and navigates to it with `navigate` once
+/// rendered.
+#[hook]
+fn use_redirect<R, F>(to: R, status: u16, navigate: F)
+where
+    R: Routable + 'static,
+    F: 'static + FnOnce(&Navigator, &R),
+{
+    let navigator = use_navigator().expect_throw("failed to read history.");
+
+    let response = use_server_response();
+    response.set_status(status);
+    response.insert_header("Location", navigator.prefix_basename(&to.to_path()));
+
+    use_effect(move || {
+        navigate(&navigator, &to);
+
+        || {}
+    });
+}
@@ -1,10 +1,20 @@
 //! Hooks to access router state and navigate between pages.
 
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use yew::prelude::*;
+use yew::suspense::SuspensionResult;
 
+use crate::blocker::{PendingNavigation, ShouldBlock};
 use crate::history::*;
-use crate::navigator::Navigator;
-use crate::routable::Routable;
+use crate::loader::{LoaderContext, RouteLoader};
+use crate::navigator::{NavigationResult, Navigator, NavigatorKind};
+use crate::query::Raw;
+use crate::routable::{AnyRoute, Routable};
 use crate::router::{LocationContext, NavigatorContext};
 
 /// A hook to access the [`Navigator`].
@@ -38,3 +48,314 @@ where
 
     R::recognize(&path)
 }
+
+/// A hook to block navigations, such as to ask for confirmation before unsaved changes are lost.
+///
+/// `should_block` is called with the current and the next location of every navigation made with
+/// the [`Navigator`], including [`Link`](crate::components::Link)s, and of the back and forward
+/// buttons of the browser. If it returns `true`, the navigation is not made and is returned by
+/// the hook instead, so it can be [proceeded with](PendingNavigation::proceed) or
+/// [reset](PendingNavigation::reset).
+///
+/// Navigations of the browser to entries the router has not seen, such as after the page has
+/// been reloaded, cannot be blocked, and neither can the page being closed or reloaded.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use yew_router::prelude::*;
+/// #[component]
+/// fn Form() -> Html {
+///     let dirty = use_state(|| false);
+///     let pending = use_blocker({
+///         let dirty = dirty.clone();
+///         move |current, next| *dirty && current.path() != next.path()
+///     });
+///
+///     html! {
+///         <input oninput={move |_| dirty.set(true)} />
+///         if let Some(pending) = pending {
+///             <dialog open=true>
+///                 <p>{ "Discard your changes?" }</p>
+///                 <button onclick={let pending = pending.clone(); move |_| pending.proceed()}>
+///                     { "Discard" }
+///                 </button>
+///                 <button onclick={move |_| pending.reset()}>{ "Stay" }</button>
+///             </dialog>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_blocker<F>(should_block: F) -> Option<PendingNavigation>
+where
+    F: 'static + Fn(&Location, &Location) -> bool,
+{
+    let navigator = use_navigator();
+    let pending = use_state(|| None::<PendingNavigation>);
+    // The blocker always checks with the latest `should_block`.
+    let should_block_ref: ShouldBlock =
+        use_mut_ref(|| Box::new(|_: &Location, _: &Location| false) as _);
+    *should_block_ref.borrow_mut() = Box::new(should_block);
+
+    {
+        let set_pending = pending.setter();
+        use_effect_with(navigator.map(|m| m.blockers().clone()), move |blockers| {
+            let blocker = blockers.clone().map(|blockers| {
+                let id = blockers.register(
+                    should_block_ref,
+                    Callback::from(move |m| set_pending.set(m)),
+                );
+                (blockers, id)
+            });
+
+            move || {
+                if let Some((blockers, id)) = blocker {
+                    blockers.unregister(id);
+                }
+            }
+        });
+    }
+
+    (*pending).clone()
+}
+
+/// A handle to set the query of the current location, returned by [`use_query`].
+///
+/// The query is set for the path of the current location, so only the query of the URL changes.
+/// The hash fragment of the location is kept. Its state is only kept by a setter returned by
+/// [`with_state`](Self::with_state), as the history only hands out states of a known type.
+pub struct QuerySetter<Q> {
+    navigator: Navigator,
+    location: Location,
+    navigate: NavigateFn,
+    _marker: PhantomData<fn(&Q)>,
+}
+
+type NavigateFn = fn(&Navigator, &AnyRoute, Option<Raw<String>>, &Location, bool);
+
+/// The state of locations that have no state.
+#[derive(Clone)]
+enum NoState {}
+
+/// Navigates to `route` with `query`, keeping the state of `location` if it is of type `S`.
+fn navigate_with_state<S>(
+    navigator: &Navigator,
+    route: &AnyRoute,
+    query: Option<Raw<String>>,
+    location: &Location,
+    replace: bool,
+) where
+    S: Clone + 'static,
+{
+    let state = location.state::<S>().map(|m| (*m).clone());
+
+    match (query, state) {
+        (None, None) if replace => navigator.replace(route),
+        (None, None) => navigator.push(route),
+        (None, Some(state)) if replace => navigator.replace_with_state(route, state),
+        (None, Some(state)) => navigator.push_with_state(route, state),
+        (Some(query), None) if replace => navigator
+            .replace_with_query(route, query)
+            .unwrap_or_else(|never| match never {}),
+        (Some(query), None) => navigator
+            .push_with_query(route, query)
+            .unwrap_or_else(|never| match never {}),
+        (Some(query), Some(state)) if replace => navigator
+            .replace_with_query_and_state(route, query, state)
+            .unwrap_or_else(|never| match never {}),
+        (Some(query), Some(state)) => navigator
+            .push_with_query_and_state(route, query, state)
+            .unwrap_or_else(|never| match never {}),
+    }
+}
+
+impl<Q> QuerySetter<Q>
+where
+    Q: Serialize,
+{
+    /// Returns a setter keeping the state of the location, given that it is of type `S`.
+    pub fn with_state<S>(self) -> Self
+    where
+        S: Clone + 'static,
+    {
+        Self {
+            navigate: navigate_with_state::<S>,
+            ..self
+        }
+    }
+
+    /// Pushes an entry with the current path and `query`.
+    pub fn push(&self, query: &Q) -> NavigationResult<()> {
+        self.navigate(query, false)
+    }
+
+    /// Replaces the current entry with one with the current path and `query`.
+    pub fn replace(&self, query: &Q) -> NavigationResult<()> {
+        self.navigate(query, true)
+    }
+
+    fn navigate(&self, query: &Q, replace: bool) -> NavigationResult<()> {
+        let navigator = &self.navigator;
+        let mut path = navigator
+            .strip_basename(self.location.path().into())
+            .into_owned();
+        let mut query = serde_urlencoded::to_string(query)?;
+
+        // A hash history keeps the path in the hash of the URL, so its hash fragment follows the
+        // query instead. A memory history has no hash fragment.
+        let hash = self.location.hash();
+        match navigator.kind() {
+            NavigatorKind::Hash => query.push_str(hash),
+            NavigatorKind::Browser | NavigatorKind::Memory => path.push_str(hash),
+        }
+
+        let route = AnyRoute::new(path);
+        let query = Some(Raw(query)).filter(|m| !m.0.is_empty());
+        (self.navigate)(navigator, &route, query, &self.location, replace);
+
+        Ok(())
+    }
+}
+
+impl<Q> Clone for QuerySetter<Q> {
+    fn clone(&self) -> Self {
+        Self {
+            navigator: self.navigator.clone(),
+            location: self.location.clone(),
+            navigate: self.navigate,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Q> PartialEq for QuerySetter<Q> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.navigator == rhs.navigator && self.location == rhs.location
+    }
+}
+
+impl<Q> fmt::Debug for QuerySetter<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuerySetter")
+            .field("location", &self.location)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A hook to read and set the query of the current location.
+///
+/// Returns the query decoded as `Q`, or the error it failed to decode with, and a
+/// [`QuerySetter`] to change it. Returns [`None`] if there's no available location.
+///
+/// # Example
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// # use yew_router::prelude::*;
+/// #[derive(Serialize, Deserialize)]
+/// struct Pagination {
+///     page: u32,
+/// }
+///
+/// #[component]
+/// fn Posts() -> Html {
+///     let (pagination, set_pagination) = use_query::<Pagination>().unwrap();
+///     // The first page has no query.
+///     let page = pagination.map(|m| m.page).unwrap_or(1);
+///
+///     let onclick = move |_| {
+///         set_pagination.push(&Pagination { page: page + 1 }).unwrap();
+///     };
+///
+///     html! {
+///         <p>{ format!("Page {page}") }</p>
+///         <button {onclick}>{ "Next page" }</button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_query<Q>() -> Option<(NavigationResult<Q>, QuerySetter<Q>)>
+where
+    Q: Serialize + DeserializeOwned + 'static,
+{
+    let navigator = use_navigator()?;
+    let location = use_location()?;
+    let query = location.query::<Q>();
+
+    Some((
+        query,
+        QuerySetter {
+            navigator,
+            location,
+            navigate: navigate_with_state::<NoState>,
+            _marker: PhantomData,
+        },
+    ))
+}
+
+/// A hook to access the data loaded for the route rendered by a [`Switch`](crate::Switch).
+///
+/// The hook suspends until the data is loaded by the [`RouteLoader`] of `R`, so the
+/// [`Switch`](crate::Switch) needs to be rendered inside a [`Suspense`]. It returns [`None`] if
+/// it is not rendered for a route of type `R`.
+///
+/// # Example
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// # use yew_router::prelude::*;
+/// #[derive(Clone, PartialEq, Routable)]
+/// #[loader]
+/// enum Route {
+///     #[at("/posts/{id}")]
+///     Post { id: u32 },
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     title: String,
+/// }
+///
+/// impl RouteLoader for Route {
+///     type Data = Post;
+///
+///     async fn load(self) -> Post {
+///         let Route::Post { id } = self;
+///         Post {
+///             title: format!("Post {id}"),
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn PostPage() -> HtmlResult {
+///     let post = use_loader_data::<Route>()?.unwrap();
+///
+///     Ok(html! { <h1>{ &post.title }</h1> })
+/// }
+///
+/// #[component]
+/// fn App() -> Html {
+///     html! {
+///         <BrowserRouter>
+///             <Suspense fallback={html! { <p>{ "Loading..." }</p> }}>
+///                 <Switch<Route> render={|_| html! { <PostPage /> }} />
+///             </Suspense>
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_loader_data<R>() -> SuspensionResult<Option<Rc<R::Data>>>
+where
+    R: RouteLoader,
+{
+    match use_context::<LoaderContext<R>>() {
+        Some(m) => m.data().map(Some),
+        None => Ok(None),
+    }
+}
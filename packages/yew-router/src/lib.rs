@@ -68,18 +68,24 @@ extern crate self as yew_router;
 #[doc(hidden)]
 #[path = "macro_helpers.rs"]
 pub mod __macro;
+mod blocker;
 pub mod components;
 pub mod hooks;
+mod loader;
 pub mod navigator;
 mod routable;
 pub mod router;
 pub mod scope_ext;
+#[cfg(feature = "ssr")]
+pub mod ssg;
 pub mod switch;
 pub mod utils;
 
+pub use blocker::PendingNavigation;
+pub use loader::RouteLoader;
 pub use routable::{AnyRoute, Routable};
 pub use router::{BrowserRouter, HashRouter, Router};
-pub use switch::Switch;
+pub use switch::{Guard, Switch};
 
 pub mod history {
     //! A module that provides universal session history and location information.
@@ -103,10 +109,12 @@ pub mod prelude {
 
     #[doc(no_inline)]
     pub use crate::Routable;
-    pub use crate::components::{Link, Redirect};
+    pub use crate::components::{Link, NavLink, NavLinkMatch, Outlet, Redirect};
     pub use crate::history::Location;
     pub use crate::hooks::*;
     pub use crate::navigator::{NavigationError, NavigationResult, Navigator};
     pub use crate::scope_ext::{LocationHandle, NavigatorHandle, RouterScopeExt};
-    pub use crate::{BrowserRouter, HashRouter, Router, Switch};
+    pub use crate::{
+        BrowserRouter, Guard, HashRouter, PendingNavigation, RouteLoader, Router, Switch,
+    };
 }
@@ -0,0 +1,190 @@
+//! Loading of the data of a route before it is rendered.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use yew::prelude::*;
+use yew::suspense::{Suspension, SuspensionResult};
+
+use crate::Routable;
+
+/// Loads the data of a [`Routable`] before its route is rendered.
+///
+/// Loaders are enabled by marking the routes with `#[loader]` when deriving [`Routable`]. A
+/// [`Switch`](crate::Switch) then starts loading the data as soon as a route is matched, and the
+/// components rendered for the route read it with
+/// [`use_loader_data`](crate::hooks::use_loader_data), which suspends until it is loaded.
+///
+/// During server-side rendering, the data is loaded before the route is rendered and is sent to
+/// the client with the page, the same way as
+/// [`use_prepared_state`](yew::functional::use_prepared_state). The client does not load it again
+/// when hydrating.
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use yew_router::prelude::*;
+/// #[derive(Clone, PartialEq, Routable)]
+/// #[loader]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/posts/{id}")]
+///     Post { id: u32 },
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// enum RouteData {
+///     Home,
+///     Post { title: String },
+/// }
+///
+/// impl RouteLoader for Route {
+///     type Data = RouteData;
+///
+///     async fn load(self) -> RouteData {
+///         match self {
+///             Route::Home => RouteData::Home,
+///             Route::Post { id } => RouteData::Post {
+///                 title: format!("Post {id}"),
+///             },
+///         }
+///     }
+/// }
+/// ```
+pub trait RouteLoader: Routable + 'static {
+    /// The data loaded for a route.
+    type Data: Serialize + DeserializeOwned + 'static;
+
+    /// Loads the data of the route.
+    fn load(self) -> impl Future<Output = Self::Data> + 'static;
+}
+
+/// The data of a route, which is loaded on the client unless it has been sent with the page.
+struct LoadState<D> {
+    data: Rc<RefCell<Option<Rc<D>>>>,
+    suspension: Option<Suspension>,
+}
+
+impl<D: 'static> LoadState<D> {
+    fn loaded(data: Rc<D>) -> Self {
+        Self {
+            data: Rc::new(RefCell::new(Some(data))),
+            suspension: None,
+        }
+    }
+
+    fn load<R>(route: R) -> Self
+    where
+        R: RouteLoader<Data = D>,
+    {
+        let data = Rc::new(RefCell::new(None));
+        let suspension = Suspension::from_future({
+            let data = data.clone();
+            async move {
+                let loaded = route.load().await;
+                *data.borrow_mut() = Some(Rc::new(loaded));
+            }
+        });
+
+        Self {
+            data,
+            suspension: Some(suspension),
+        }
+    }
+}
+
+/// The data of the route rendered by a [`Switch`](crate::Switch), read by
+/// [`use_loader_data`](crate::hooks::use_loader_data).
+pub(crate) struct LoaderContext<R: RouteLoader> {
+    state: Rc<LoadState<R::Data>>,
+    _marker: PhantomData<R>,
+}
+
+impl<R: RouteLoader> LoaderContext<R> {
+    /// Returns the data, or suspends until it is loaded.
+    pub fn data(&self) -> SuspensionResult<Rc<R::Data>> {
+        let data = self.state.data.borrow().clone();
+
+        match (data, &self.state.suspension) {
+            (Some(data), _) => Ok(data),
+            (None, Some(suspension)) => Err(suspension.clone()),
+            (None, None) => unreachable!("the data of a route is either loaded or loading"),
+        }
+    }
+}
+
+impl<R: RouteLoader> Clone for LoaderContext<R> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R: RouteLoader> PartialEq for LoaderContext<R> {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &rhs.state)
+    }
+}
+
+impl<R: RouteLoader> fmt::Debug for LoaderContext<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoaderContext")
+            .field("loaded", &self.state.data.borrow().is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Props for [`LoaderProvider`]
+#[derive(Properties, PartialEq)]
+pub(crate) struct LoaderProviderProps<R: RouteLoader> {
+    pub route: R,
+    pub children: Html,
+}
+
+/// Loads the data of a route and provides it to the components rendered for the route.
+#[component]
+pub(crate) fn LoaderProvider<R>(props: &LoaderProviderProps<R>) -> HtmlResult
+where
+    R: RouteLoader,
+{
+    // The route is only used by the closure on the server, which is omitted on the client.
+    #[allow(unused_variables)]
+    let route = props.route.clone();
+    let prepared = use_prepared_state!(
+        props.route.to_path(),
+        async move |_| -> <R as RouteLoader>::Data { route.load().await }
+    )?;
+
+    let state = use_memo(props.route.clone(), move |route| match prepared {
+        Some(data) => LoadState::loaded(data),
+        None => LoadState::load(route.clone()),
+    });
+    let context = LoaderContext::<R> {
+        state,
+        _marker: PhantomData,
+    };
+
+    Ok(html! {
+        <ContextProvider<LoaderContext<R>> {context}>
+            {props.children.clone()}
+        </ContextProvider<LoaderContext<R>>>
+    })
+}
+
+/// Wraps the rendered route with a [`LoaderProvider`], used by `#[derive(Routable)]`.
+pub fn provide_loader<R>(route: R, children: Html) -> Html
+where
+    R: RouteLoader,
+{
+    html! {
+        <LoaderProvider<R> {route}>
+            {children}
+        </LoaderProvider<R>>
+    }
+}
@@ -9,7 +9,10 @@ pub fn encode_path_for_url(path: &str) -> String {
 
 use std::collections::HashMap;
 
+pub use yew::Html;
+
 use crate::Routable;
+pub use crate::loader::provide_loader;
 
 // re-export Router because the macro needs to access it
 pub type Router = matchit::Router<String>;
@@ -38,3 +41,36 @@ pub fn recognize_with_router<R: Routable>(router: &Router, pathname: &str) -> Op
         Err(_) => R::not_found_route(),
     }
 }
+
+/// Lists the routes of the nested `Routable` mounted at `prefix`.
+///
+/// The routes are leaked as the derived `routes()` returns `&'static str`s, but they are only
+/// built once per `Routable` type.
+pub fn nested_routes<R: Routable>(prefix: &'static str) -> Vec<&'static str> {
+    let mut routes = Vec::new();
+    for route in R::routes() {
+        if route == "/" {
+            routes.push(if prefix.is_empty() { "/" } else { prefix });
+        }
+        routes.push(format!("{prefix}{route}").leak());
+    }
+
+    routes
+}
+
+/// Returns the path of the nested route if `path` starts with `prefix`.
+pub fn strip_nested_prefix<'a>(path: &'a str, prefix: &str) -> Option<&'a str> {
+    match path.strip_prefix(prefix)? {
+        "" => Some("/"),
+        rest if rest.starts_with('/') => Some(rest),
+        _ => None,
+    }
+}
+
+/// Joins `prefix` and the path of the nested route.
+pub fn join_nested_path(prefix: &str, path: &str) -> String {
+    match path {
+        "/" if !prefix.is_empty() => prefix.to_owned(),
+        _ => format!("{prefix}{path}"),
+    }
+}
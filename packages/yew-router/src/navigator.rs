@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 
+use crate::blocker::{Blockers, NavigationKind, next_location};
 use crate::history::{AnyHistory, History, HistoryError, HistoryResult};
-use crate::query::ToQuery;
+use crate::query::{Raw, ToQuery};
 use crate::routable::Routable;
 
 pub type NavigationError = HistoryError;
@@ -19,20 +20,29 @@ pub enum NavigatorKind {
 }
 
 /// A struct to navigate between locations.
+///
+/// Navigations blocked by [`use_blocker`](crate::hooks::use_blocker) are not made until they are
+/// proceeded with.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Navigator {
     inner: AnyHistory,
     basename: Option<String>,
+    blockers: Blockers,
 }
 
 impl Navigator {
-    pub(crate) fn new(history: AnyHistory, basename: Option<String>) -> Self {
+    pub(crate) fn new(history: AnyHistory, basename: Option<String>, blockers: Blockers) -> Self {
         Self {
             inner: history,
             basename,
+            blockers,
         }
     }
 
+    pub(crate) fn blockers(&self) -> &Blockers {
+        &self.blockers
+    }
+
     /// Returns basename of current navigator.
     pub fn basename(&self) -> Option<&str> {
         self.basename.as_deref()
@@ -52,7 +62,15 @@ impl Navigator {
     ///
     /// See: <https://developer.mozilla.org/en-US/docs/Web/API/History/go>
     pub fn go(&self, delta: isize) {
-        self.inner.go(delta);
+        let to = self
+            .blockers
+            .is_blocking()
+            .then(|| self.blockers.entry(delta))
+            .flatten();
+        let history = self.inner.clone();
+
+        self.blockers
+            .navigate(NavigationKind::Go(delta), to, move || history.go(delta));
     }
 
     /// Pushes a [`Routable`] entry.
@@ -60,7 +78,9 @@ impl Navigator {
     where
         R: Routable,
     {
-        self.inner.push(self.prefix_basename(&route.to_path()));
+        self.navigate(NavigationKind::Push, route, None, |history, path, _| {
+            history.push(path)
+        });
     }
 
     /// Replaces the current history entry with provided [`Routable`] and [`None`] state.
@@ -68,7 +88,22 @@ impl Navigator {
     where
         R: Routable,
     {
-        self.inner.replace(self.prefix_basename(&route.to_path()));
+        self.navigate(NavigationKind::Replace, route, None, |history, path, _| {
+            history.replace(path)
+        });
+    }
+
+    /// Replaces the current history entry with provided [`Routable`], without checking the
+    /// navigation with the blockers.
+    pub(crate) fn replace_unblocked<R>(&self, route: &R)
+    where
+        R: Routable,
+    {
+        let path = self.prefix_basename(&route.to_path()).into_owned();
+        let history = self.inner.clone();
+
+        self.blockers
+            .navigate(NavigationKind::Replace, None, move || history.replace(path));
     }
 
     /// Pushes a [`Routable`] entry with state.
@@ -77,8 +112,12 @@ impl Navigator {
         R: Routable,
         T: 'static,
     {
-        self.inner
-            .push_with_state(self.prefix_basename(&route.to_path()), state);
+        self.navigate(
+            NavigationKind::Push,
+            route,
+            None,
+            move |history, path, _| history.push_with_state(path, state),
+        );
     }
 
     /// Replaces the current history entry with provided [`Routable`] and state.
@@ -87,8 +126,12 @@ impl Navigator {
         R: Routable,
         T: 'static,
     {
-        self.inner
-            .replace_with_state(self.prefix_basename(&route.to_path()), state);
+        self.navigate(
+            NavigationKind::Replace,
+            route,
+            None,
+            move |history, path, _| history.replace_with_state(path, state),
+        );
     }
 
     /// Same as `.push()` but affix the queries to the end of the route.
@@ -97,8 +140,19 @@ impl Navigator {
         R: Routable,
         Q: ToQuery,
     {
-        self.inner
-            .push_with_query(self.prefix_basename(&route.to_path()), query)
+        let query = query.to_query()?.into_owned();
+        self.navigate(
+            NavigationKind::Push,
+            route,
+            Some(query),
+            |history, path, query| {
+                history
+                    .push_with_query(path, Raw(query))
+                    .unwrap_or_else(|never| match never {})
+            },
+        );
+
+        Ok(())
     }
 
     /// Same as `.replace()` but affix the queries to the end of the route.
@@ -107,8 +161,19 @@ impl Navigator {
         R: Routable,
         Q: ToQuery,
     {
-        self.inner
-            .replace_with_query(self.prefix_basename(&route.to_path()), query)
+        let query = query.to_query()?.into_owned();
+        self.navigate(
+            NavigationKind::Replace,
+            route,
+            Some(query),
+            |history, path, query| {
+                history
+                    .replace_with_query(path, Raw(query))
+                    .unwrap_or_else(|never| match never {})
+            },
+        );
+
+        Ok(())
     }
 
     /// Same as `.push_with_state()` but affix the queries to the end of the route.
@@ -123,8 +188,19 @@ impl Navigator {
         Q: ToQuery,
         T: 'static,
     {
-        self.inner
-            .push_with_query_and_state(self.prefix_basename(&route.to_path()), query, state)
+        let query = query.to_query()?.into_owned();
+        self.navigate(
+            NavigationKind::Push,
+            route,
+            Some(query),
+            move |history, path, query| {
+                history
+                    .push_with_query_and_state(path, Raw(query), state)
+                    .unwrap_or_else(|never| match never {})
+            },
+        );
+
+        Ok(())
     }
 
     /// Same as `.replace_with_state()` but affix the queries to the end of the route.
@@ -139,11 +215,37 @@ impl Navigator {
         Q: ToQuery,
         T: 'static,
     {
-        self.inner.replace_with_query_and_state(
-            self.prefix_basename(&route.to_path()),
-            query,
-            state,
-        )
+        let query = query.to_query()?.into_owned();
+        self.navigate(
+            NavigationKind::Replace,
+            route,
+            Some(query),
+            move |history, path, query| {
+                history
+                    .replace_with_query_and_state(path, Raw(query), state)
+                    .unwrap_or_else(|never| match never {})
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Navigates to `route` with `navigate`, unless the navigation is blocked.
+    fn navigate<R, F>(&self, kind: NavigationKind, route: &R, query: Option<String>, navigate: F)
+    where
+        R: Routable,
+        F: 'static + FnOnce(&AnyHistory, &str, &str),
+    {
+        let path = self.prefix_basename(&route.to_path()).into_owned();
+        let to = self
+            .blockers
+            .is_blocking()
+            .then(|| next_location(&path, query.as_deref()));
+        let history = self.inner.clone();
+
+        self.blockers.navigate(kind, to, move || {
+            navigate(&history, &path, query.as_deref().unwrap_or_default())
+        });
     }
 
     /// Returns the Navigator kind.
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use yew::Html;
 pub use yew_router_macro::Routable;
 
 /// Marks an `enum` as routable.
@@ -28,6 +29,23 @@ pub trait Routable: Clone + PartialEq {
 
     /// Match a route based on the path
     fn recognize(pathname: &str) -> Option<Self>;
+
+    /// Returns the path of the nested route if the route is a `#[nested]` variant.
+    ///
+    /// The path is relative to the prefix of the variant and is rendered by an
+    /// [`Outlet`](crate::components::Outlet).
+    fn nested_path(&self) -> Option<String> {
+        None
+    }
+
+    /// Wraps the [`Html`] rendered for the route by a [`Switch`](crate::Switch).
+    ///
+    /// Routes marked with `#[loader]` are wrapped with the provider of the data loaded by their
+    /// [`RouteLoader`](crate::RouteLoader).
+    #[doc(hidden)]
+    fn __with_loader(&self, html: Html) -> Html {
+        html
+    }
 }
 
 /// A special route that accepts any route.
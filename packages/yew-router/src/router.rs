@@ -6,6 +6,7 @@ use gloo::history::query::Raw;
 use yew::prelude::*;
 use yew::virtual_dom::AttrValue;
 
+use crate::blocker::Blockers;
 use crate::history::{AnyHistory, BrowserHistory, HashHistory, History, Location};
 use crate::navigator::Navigator;
 use crate::utils::{base_url, strip_slash_suffix};
@@ -75,7 +76,8 @@ fn base_router(props: &RouterProps) -> Html {
     } = props.clone();
 
     let basename = basename.map(|m| strip_slash_suffix(&m).to_owned());
-    let navigator = Navigator::new(history.clone(), basename.clone());
+    let blockers = use_state(Blockers::default);
+    let navigator = Navigator::new(history.clone(), basename.clone(), (*blockers).clone());
 
     let old_basename = use_mut_ref(|| Option::<String>::None);
     let mut old_basename = old_basename.borrow_mut();
@@ -86,6 +88,7 @@ fn base_router(props: &RouterProps) -> Html {
         let old_navigator = Navigator::new(
             history.clone(),
             old_basename.as_ref().or(basename.as_ref()).cloned(),
+            Blockers::default(),
         );
         old_basename.clone_from(&basename);
         let location = history.location();
@@ -113,15 +116,23 @@ fn base_router(props: &RouterProps) -> Html {
 
     {
         let loc_ctx_dispatcher = loc_ctx.dispatcher();
+        let blockers = (*blockers).clone();
 
         use_effect_with(history, move |history| {
             let history = history.clone();
             // Force location update when history changes.
+            blockers.reset(history.location());
             loc_ctx_dispatcher.dispatch(history.location());
 
             let history_cb = {
                 let history = history.clone();
-                move || loc_ctx_dispatcher.dispatch(history.location())
+                move || {
+                    let location = history.location();
+                    // Navigations blocked by `use_blocker` are reverted.
+                    if blockers.observe(location.clone(), &history) {
+                        loc_ctx_dispatcher.dispatch(location);
+                    }
+                }
             };
 
             let listener = history.listen(history_cb);
@@ -0,0 +1,310 @@
+//! Static site generation for applications using [`Routable`] routes.
+//!
+//! The [`StaticSiteGenerator`] renders each route of an application with
+//! [`ServerRenderer`](yew::ServerRenderer) and writes it into an output directory as
+//! `<path>/index.html`, so the directory can be served by any static file server:
+//!
+//! ```no_run
+//! # use std::collections::HashMap;
+//! # use yew::prelude::*;
+//! # use yew_router::prelude::*;
+//! use yew_router::ssg::StaticSiteGenerator;
+//!
+//! #[derive(Routable, PartialEq, Clone, Debug)]
+//! enum Route {
+//!     #[at("/")]
+//!     Home,
+//!     #[at("/posts/{id}")]
+//!     Post { id: u32 },
+//! }
+//!
+//! #[component]
+//! fn Content() -> Html {
+//!     html! {
+//!         <Switch<Route> render={|route| html! { format!("{route:?}") }} />
+//!     }
+//! }
+//!
+//! # async fn generate() -> std::io::Result<()> {
+//! StaticSiteGenerator::<Route, Content>::new("dist")
+//!     .params(|pattern| match pattern {
+//!         "/posts/{id}" => (1..=3)
+//!             .map(|id| HashMap::from([("id".to_owned(), id.to_string())]))
+//!             .collect(),
+//!         _ => Vec::new(),
+//!     })
+//!     .generate()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::path::{Component as PathComponent, Path, PathBuf};
+use std::{fs, io};
+
+use yew::prelude::*;
+use yew::{BaseComponent, ServerRenderer};
+
+use crate::__macro::{decode_for_url, encode_for_url, encode_path_for_url};
+use crate::Routable;
+use crate::history::{AnyHistory, MemoryHistory};
+use crate::router::Router;
+
+/// The values of the parameters of a route, by the name of their segments.
+pub type RouteParams = HashMap<String, String>;
+
+type ParamsFn = Box<dyn Fn(&str) -> Vec<RouteParams>>;
+
+/// Props for [`StaticApp`].
+#[derive(Properties, PartialEq)]
+struct StaticAppProps {
+    path: AttrValue,
+}
+
+/// Renders `COMP` in a [`Router`] at `path`.
+#[component]
+fn StaticApp<COMP>(props: &StaticAppProps) -> Html
+where
+    COMP: BaseComponent<Properties: Default>,
+{
+    let history = use_memo(props.path.clone(), |path| {
+        AnyHistory::from(MemoryHistory::with_entries([path.as_str()]))
+    });
+
+    html! {
+        <Router history={(*history).clone()}>
+            <COMP ..Default::default() />
+        </Router>
+    }
+}
+
+/// A page written by the [`StaticSiteGenerator`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedPage<R> {
+    /// The route the page has been rendered for.
+    pub route: R,
+    /// The path of the route.
+    pub path: String,
+    /// The file the page has been written into.
+    pub file: PathBuf,
+}
+
+/// Renders every route of `R` into static HTML files.
+///
+/// Each page is rendered with `COMP` in a [`Router`] whose history is at the path of the route,
+/// so the [`Switch`](crate::Switch) in `COMP` renders the page of the route. `COMP` is rendered
+/// with its default properties.
+///
+/// Routes without parameters are rendered once. The values of the parameters of the other routes
+/// are supplied with [`params`](Self::params), and a route is skipped if no values are supplied
+/// for it.
+pub struct StaticSiteGenerator<R, COMP>
+where
+    R: Routable,
+    COMP: BaseComponent<Properties: Default>,
+{
+    out_dir: PathBuf,
+    params: ParamsFn,
+    template: Option<String>,
+    hydratable: bool,
+    _marker: PhantomData<(R, COMP)>,
+}
+
+impl<R, COMP> StaticSiteGenerator<R, COMP>
+where
+    R: Routable,
+    COMP: BaseComponent<Properties: Default>,
+{
+    /// Creates a generator writing the pages into `out_dir`.
+    pub fn new(out_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            out_dir: out_dir.into(),
+            params: Box::new(|_| Vec::new()),
+            template: None,
+            hydratable: true,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the function supplying the values of the parameters of the routes.
+    ///
+    /// The function is called with the pattern of each route with parameters, such as
+    /// `/posts/{id}`, and returns the values for each page of the route. Each page is
+    /// rendered at the path with the parameters replaced by their percent-encoded values.
+    pub fn params<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(&str) -> Vec<RouteParams>,
+    {
+        self.params = Box::new(f);
+
+        self
+    }
+
+    /// Sets the HTML document the pages are rendered into.
+    ///
+    /// The rendered markup is inserted right after the `<body>` tag of the template, which may
+    /// have attributes. Without a template, the files only contain the rendered markup.
+    pub fn template(mut self, template: impl Into<String>) -> Self {
+        self.template = Some(template.into());
+
+        self
+    }
+
+    /// Sets whether the pages are rendered with hydration support.
+    ///
+    /// Defaults to `true`.
+    pub fn hydratable(mut self, val: bool) -> Self {
+        self.hydratable = val;
+
+        self
+    }
+
+    /// Lists the route and path of each page.
+    ///
+    /// Returns an error if the values supplied for a route do not include all of its parameters,
+    /// or if the resulting path is not recognised as the route.
+    pub fn pages(&self) -> io::Result<Vec<(R, String)>> {
+        let mut pages = Vec::new();
+
+        for pattern in R::routes() {
+            let paths = if has_params(pattern) {
+                let params = (self.params)(pattern);
+                if params.is_empty() {
+                    tracing::warn!("no parameters are supplied for {pattern}, it is skipped");
+                }
+
+                params
+                    .iter()
+                    .map(|m| fill_params(pattern, m))
+                    .collect::<io::Result<Vec<_>>>()?
+            } else {
+                vec![pattern.to_owned()]
+            };
+
+            for path in paths {
+                let route = R::recognize(&path)
+                    .filter(|m| !has_params(pattern) || R::not_found_route().as_ref() != Some(m))
+                    .ok_or_else(|| {
+                        invalid_input(format!("{path} is not recognised as a route of {pattern}"))
+                    })?;
+                let path = route.to_path();
+                pages.push((route, path));
+            }
+        }
+
+        Ok(pages)
+    }
+
+    /// Renders the pages and writes them into the output directory.
+    ///
+    /// The page at `/` is written into `index.html` and every other page into
+    /// `<path>/index.html`, with the path decoded.
+    pub async fn generate(self) -> io::Result<Vec<GeneratedPage<R>>> {
+        let (before, after) = match self.template {
+            Some(ref m) => {
+                let (before, after) = split_after_body(m)
+                    .ok_or_else(|| invalid_input("the template has no <body> tag".to_owned()))?;
+                (before.to_owned(), after.to_owned())
+            }
+            None => (String::new(), String::new()),
+        };
+
+        let mut generated = Vec::new();
+
+        for (route, path) in self.pages()? {
+            let file = page_file(&self.out_dir, &path)?;
+
+            let props_path = path.clone();
+            let body = ServerRenderer::<StaticApp<COMP>>::with_props(move || StaticAppProps {
+                path: props_path.into(),
+            })
+            .hydratable(self.hydratable)
+            .render()
+            .await;
+
+            if let Some(dir) = file.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&file, format!("{before}{body}{after}"))?;
+
+            generated.push(GeneratedPage { route, path, file });
+        }
+
+        Ok(generated)
+    }
+}
+
+fn invalid_input(reason: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, reason)
+}
+
+fn has_params(pattern: &str) -> bool {
+    pattern.contains('{')
+}
+
+/// Splits `template` right after its `<body>` tag, which may have attributes.
+fn split_after_body(template: &str) -> Option<(&str, &str)> {
+    // Tag names are case-insensitive, and lowercasing keeps the byte offsets.
+    let lowercase = template.to_ascii_lowercase();
+    let start = lowercase.match_indices("<body").map(|(i, _)| i).find(|i| {
+        lowercase[i + "<body".len()..]
+            .starts_with(|c: char| c == '>' || c == '/' || c.is_ascii_whitespace())
+    })?;
+    let end = start + lowercase[start..].find('>')? + 1;
+
+    Some(template.split_at(end))
+}
+
+/// Replaces the parameters of `pattern`, such as `{id}` and `{*rest}`, with their values.
+///
+/// The values are percent-encoded, except for the slashes of the values of wildcard parameters.
+fn fill_params(pattern: &str, params: &RouteParams) -> io::Result<String> {
+    let mut path = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+
+    while let Some((before, after)) = rest.split_once('{') {
+        let (name, after) = after
+            .split_once('}')
+            .ok_or_else(|| invalid_input(format!("{pattern} is not a valid pattern")))?;
+        let (name, is_wildcard) = match name.strip_prefix('*') {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        let value = params.get(name).ok_or_else(|| {
+            invalid_input(format!("no value is supplied for {name} of {pattern}"))
+        })?;
+
+        path.push_str(before);
+        if is_wildcard {
+            path.push_str(&encode_path_for_url(value));
+        } else {
+            path.push_str(&encode_for_url(value));
+        }
+        rest = after;
+    }
+    path.push_str(rest);
+
+    Ok(path)
+}
+
+/// Returns the file the page at `path` is written into.
+///
+/// The file is named after the decoded path, as static file servers decode the path of a request
+/// before looking up its file.
+fn page_file(out_dir: &Path, path: &str) -> io::Result<PathBuf> {
+    let decoded = decode_for_url(path)
+        .map_err(|_| invalid_input(format!("{path} is not a valid UTF-8 path")))?;
+    let relative = Path::new(decoded.trim_start_matches('/'));
+    if !relative
+        .components()
+        .all(|m| matches!(m, PathComponent::Normal(_)))
+    {
+        return Err(invalid_input(format!(
+            "{path} cannot be written into the output directory"
+        )));
+    }
+
+    Ok(out_dir.join(relative).join("index.html"))
+}
@@ -2,6 +2,7 @@
 
 use yew::prelude::*;
 
+use crate::components::{GuardRedirect, OutletContext};
 use crate::prelude::*;
 
 /// Props for [`Switch`]
@@ -14,6 +15,21 @@ where
     pub render: Callback<R, Html>,
     #[prop_or_default]
     pub pathname: Option<String>,
+    /// Callback deciding whether the matched route is rendered or redirected from.
+    #[prop_or_default]
+    pub guard: Option<Callback<R, Guard<R>>>,
+}
+
+/// Whether a [`Switch`] renders the matched route, returned by its
+/// [`guard`](SwitchProps::guard).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Guard<R> {
+    /// The route is rendered.
+    Allow,
+    /// The current entry of the history is replaced with the route, e.g. to send users who are
+    /// not signed in to the sign in page. The redirect is not blocked by
+    /// [`use_blocker`](crate::hooks::use_blocker).
+    Redirect(R),
 }
 
 /// A Switch that dispatches route among variants of a [`Routable`].
@@ -24,12 +40,53 @@ where
 /// Otherwise `html! {}` is rendered and a message is logged to console
 /// stating that no route can be matched.
 /// See the [crate level document][crate] for more information.
+///
+/// During server-side rendering, the status of the response is set to `404 Not Found` when no
+/// route or the `not_found` route is matched.
+///
+/// The [`guard`](SwitchProps::guard) is called with the matched route before it is rendered, and
+/// can redirect to another route instead:
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use yew_router::prelude::*;
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/admin")]
+///     Admin,
+///     #[at("/login")]
+///     Login,
+/// }
+///
+/// #[component]
+/// fn App() -> Html {
+///     let signed_in = use_state(|| false);
+///     let guard = Callback::from(move |route| match route {
+///         Route::Admin if !*signed_in => Guard::Redirect(Route::Login),
+///         _ => Guard::Allow,
+///     });
+///
+///     html! {
+///         <Switch<Route> render={|route: Route| html! { /* ... */ }} {guard} />
+///     }
+/// }
+/// ```
+///
+/// When a `#[nested]` variant is matched, the path of its nested route is provided to the
+/// [`Outlet`](crate::components::Outlet)s rendered by it.
+///
+/// For routes marked with `#[loader]`, the data of the matched route is loaded by its
+/// [`RouteLoader`] and is read with [`use_loader_data`]. The `Switch` needs to be rendered inside
+/// a [`Suspense`] while the data is loaded.
 #[component]
 pub fn Switch<R>(props: &SwitchProps<R>) -> Html
 where
     R: Routable + 'static,
 {
     let route = use_route::<R>();
+    let response = use_server_response();
 
     let route = props
         .pathname
@@ -37,8 +94,35 @@ where
         .and_then(|p| R::recognize(p))
         .or(route);
 
+    if route.is_none() || route == R::not_found_route() {
+        response.set_status(404);
+    }
+
+    let redirect = route
+        .clone()
+        .zip(props.guard.as_ref())
+        .and_then(|(route, guard)| match guard.emit(route) {
+            Guard::Allow => None,
+            Guard::Redirect(to) => Some(to),
+        });
+    if let Some(to) = redirect {
+        return html! { <GuardRedirect<R> {to} /> };
+    }
+
     match route {
-        Some(route) => props.render.emit(route),
+        Some(route) => {
+            let html = props.render.emit(route.clone());
+            let html = match route.nested_path() {
+                Some(path) => html! {
+                    <ContextProvider<OutletContext> context={OutletContext { path }}>
+                        {html}
+                    </ContextProvider<OutletContext>>
+                },
+                None => html,
+            };
+
+            route.__with_loader(html)
+        }
         None => {
             tracing::warn!("no route matched");
             Html::default()
@@ -0,0 +1,90 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::time::Duration;
+
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/form")]
+    Form,
+    #[at("/done")]
+    Done,
+}
+
+#[component]
+fn Form() -> Html {
+    let navigator = use_navigator().unwrap();
+    let pending = use_blocker(|current, next| current.path() == "/form" && next.path() != "/form");
+
+    html! {
+        <button id="done" onclick={move |_| navigator.push(&Routes::Done)}>{"Done"}</button>
+        if let Some(pending) = pending {
+            <div id="pending">{pending.to().path().to_owned()}</div>
+            <button id="proceed" onclick={let pending = pending.clone(); move |_| pending.proceed()}>
+                {"Proceed"}
+            </button>
+            <button id="reset" onclick={move |_| pending.reset()}>{"Reset"}</button>
+        }
+    }
+}
+
+#[component]
+fn Root() -> Html {
+    let history = use_memo((), |_| {
+        AnyHistory::from(MemoryHistory::with_entries(["/", "/form"]))
+    });
+
+    html! {
+        <Router history={(*history).clone()}>
+            <Switch<Routes> render={|route: Routes| html! {
+                <div id="route">{format!("{route:?}")}</div>
+                if route == Routes::Form {
+                    <Form />
+                }
+            }} />
+        </Router>
+    }
+}
+
+fn pending() -> Option<String> {
+    gloo::utils::document()
+        .get_element_by_id("pending")
+        .map(|m| m.inner_html())
+}
+
+#[test]
+async fn blocked_navigation_can_be_reset_and_proceeded() {
+    yew::Renderer::<Root>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+
+    click("#done");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+    assert_eq!(pending().as_deref(), Some("/done"));
+
+    click("#reset");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+    assert_eq!(pending(), None);
+
+    click("#done");
+    sleep(Duration::ZERO).await;
+    click("#proceed");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Done", obtain_result_by_id("route"));
+}
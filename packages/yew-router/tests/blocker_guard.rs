@@ -0,0 +1,83 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::time::Duration;
+
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/admin")]
+    Admin,
+    #[at("/login")]
+    Login,
+}
+
+#[component]
+fn Navigation() -> Html {
+    let navigator = use_navigator().unwrap();
+    // Blocks every navigation, so only the redirects of the guard are made without asking.
+    let pending = use_blocker(|current, next| current.path() != next.path());
+
+    html! {
+        <button id="admin" onclick={move |_| navigator.push(&Routes::Admin)}>{"Admin"}</button>
+        if let Some(pending) = pending {
+            <div id="pending">{pending.to().path().to_owned()}</div>
+            <button id="proceed" onclick={move |_| pending.proceed()}>{"Proceed"}</button>
+        }
+    }
+}
+
+#[component]
+fn Root() -> Html {
+    let history = use_memo((), |_| AnyHistory::from(MemoryHistory::with_entries(["/"])));
+    let guard = Callback::from(|route| match route {
+        Routes::Admin => Guard::Redirect(Routes::Login),
+        _ => Guard::Allow,
+    });
+
+    html! {
+        <Router history={(*history).clone()}>
+            <Navigation />
+            <Switch<Routes> render={|route: Routes| html! {
+                <div id="route">{format!("{route:?}")}</div>
+            }} {guard} />
+        </Router>
+    }
+}
+
+fn pending() -> Option<String> {
+    gloo::utils::document()
+        .get_element_by_id("pending")
+        .map(|m| m.inner_html())
+}
+
+#[test]
+async fn guard_redirects_are_not_blocked() {
+    yew::Renderer::<Root>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!("Home", obtain_result_by_id("route"));
+
+    click("#admin");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Home", obtain_result_by_id("route"));
+    assert_eq!(pending().as_deref(), Some("/admin"));
+
+    click("#proceed");
+    sleep(Duration::ZERO).await;
+    sleep(Duration::ZERO).await;
+    assert_eq!("Login", obtain_result_by_id("route"));
+    assert_eq!(pending(), None);
+}
@@ -0,0 +1,185 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::time::Duration;
+
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{BrowserHistory, History};
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/form")]
+    Form,
+}
+
+#[component]
+fn Home() -> Html {
+    let navigator = use_navigator().unwrap();
+
+    html! {
+        <button id="form" onclick={move |_| navigator.push(&Routes::Form)}>{"Form"}</button>
+    }
+}
+
+#[component]
+fn Form() -> Html {
+    let navigator = use_navigator().unwrap();
+    let pending = use_blocker(|current, next| current.path() == "/form" && next.path() != "/form");
+
+    html! {
+        <button id="back" onclick={let navigator = navigator.clone(); move |_| navigator.back()}>
+            {"Back"}
+        </button>
+        <button id="forward" onclick={move |_| navigator.forward()}>{"Forward"}</button>
+        if let Some(pending) = pending {
+            <div id="pending">{pending.to().path().to_owned()}</div>
+            <button id="proceed" onclick={let pending = pending.clone(); move |_| pending.proceed()}>
+                {"Proceed"}
+            </button>
+            <button id="reset" onclick={move |_| pending.reset()}>{"Reset"}</button>
+        }
+    }
+}
+
+#[component]
+fn Root() -> Html {
+    html! {
+        <BrowserRouter>
+            <Switch<Routes> render={|route: Routes| html! {
+                <div id="route">{format!("{route:?}")}</div>
+                match route {
+                    Routes::Home => <Home />,
+                    Routes::Form => <Form />,
+                }
+            }} />
+        </BrowserRouter>
+    }
+}
+
+fn pending() -> Option<String> {
+    gloo::utils::document()
+        .get_element_by_id("pending")
+        .map(|m| m.inner_html())
+}
+
+// The browser moves through the history asynchronously, and fires `popstate` once it has.
+async fn wait_for_history() {
+    sleep(Duration::from_millis(100)).await;
+}
+
+fn browser_back() {
+    gloo::utils::window().history().unwrap().back().unwrap();
+}
+
+#[test]
+async fn going_through_the_history_is_blocked() {
+    BrowserHistory::new().replace("/");
+
+    let app = yew::Renderer::<Root>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!("Home", obtain_result_by_id("route"));
+
+    click("#form");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+    let length = history_length();
+
+    // Navigator::go
+    click("#back");
+    wait_for_history().await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+    assert_eq!(pending().as_deref(), Some("/"));
+    assert_eq!(BrowserHistory::new().location().path(), "/form");
+
+    click("#reset");
+    sleep(Duration::ZERO).await;
+    assert_eq!(pending(), None);
+
+    // The back button of the browser, which is reverted.
+    browser_back();
+    wait_for_history().await;
+    wait_for_history().await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+    assert_eq!(pending().as_deref(), Some("/"));
+    assert_eq!(BrowserHistory::new().location().path(), "/form");
+
+    click("#proceed");
+    wait_for_history().await;
+    assert_eq!("Home", obtain_result_by_id("route"));
+    assert_eq!(pending(), None);
+    assert_eq!(BrowserHistory::new().location().path(), "/");
+    assert_eq!(length, history_length());
+
+    app.destroy();
+}
+
+#[test]
+async fn going_back_past_the_first_entry_of_the_router_is_blocked() {
+    // The entry before the one the router has been loaded with, such as after a reload.
+    BrowserHistory::new().replace("/");
+    BrowserHistory::new().push("/form");
+
+    let app = yew::Renderer::<Root>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+
+    browser_back();
+    wait_for_history().await;
+    wait_for_history().await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+    assert_eq!(pending().as_deref(), Some("/"));
+    assert_eq!(BrowserHistory::new().location().path(), "/form");
+
+    click("#proceed");
+    wait_for_history().await;
+    assert_eq!("Home", obtain_result_by_id("route"));
+    assert_eq!(BrowserHistory::new().location().path(), "/");
+
+    app.destroy();
+}
+
+#[test]
+async fn going_nowhere_does_not_let_the_next_navigation_through() {
+    BrowserHistory::new().replace("/");
+
+    let app = yew::Renderer::<Root>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    click("#form");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+
+    // There is no entry to go forward to.
+    click("#forward");
+    wait_for_history().await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+    assert_eq!(pending(), None);
+
+    browser_back();
+    wait_for_history().await;
+    wait_for_history().await;
+    assert_eq!("Form", obtain_result_by_id("route"));
+    assert_eq!(pending().as_deref(), Some("/"));
+    assert_eq!(BrowserHistory::new().location().path(), "/form");
+
+    app.destroy();
+}
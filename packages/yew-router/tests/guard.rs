@@ -0,0 +1,87 @@
+#![cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+
+use yew::ServerRenderer;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Routable, Debug, Clone, PartialEq)]
+enum Route {
+    #[at("/")]
+    Home,
+    #[at("/admin")]
+    Admin,
+    #[at("/login")]
+    Login,
+}
+
+#[derive(Clone)]
+struct SignedIn(bool);
+
+fn switch(route: Route) -> Html {
+    html! { <h1>{format!("{route:?}")}</h1> }
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    history: AnyHistory,
+}
+
+#[component]
+fn App(props: &AppProps) -> Html {
+    let signed_in = use_server_context::<SignedIn>().is_some_and(|m| m.0);
+    let guard = Callback::from(move |route| match route {
+        Route::Admin if !signed_in => Guard::Redirect(Route::Login),
+        _ => Guard::Allow,
+    });
+
+    html! {
+        <Router history={props.history.clone()}>
+            <Switch<Route> render={switch} {guard} />
+        </Router>
+    }
+}
+
+#[tokio::test]
+async fn allowed_route_is_rendered() {
+    let response = ServerRenderer::<App>::with_props(|| AppProps {
+        history: AnyHistory::from(MemoryHistory::with_entries(["/admin"])),
+    })
+    .hydratable(false)
+    .with_context(SignedIn(true))
+    .render_response()
+    .await;
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, "<h1>Admin</h1>");
+
+    assert_eq!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/"])),
+        })
+        .hydratable(false)
+        .with_context(SignedIn(false))
+        .render_response()
+        .await
+        .body,
+        "<h1>Home</h1>"
+    );
+}
+
+#[tokio::test]
+async fn guard_redirects() {
+    let response = ServerRenderer::<App>::with_props(|| AppProps {
+        history: AnyHistory::from(MemoryHistory::with_entries(["/admin"])),
+    })
+    .hydratable(false)
+    .with_context(SignedIn(false))
+    .render_response()
+    .await;
+
+    assert_eq!(response.status, 307);
+    assert_eq!(
+        response.headers,
+        vec![("Location".to_owned(), "/login".to_owned())]
+    );
+    assert_eq!(response.body, "");
+}
@@ -0,0 +1,113 @@
+#![cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+
+use serde::{Deserialize, Serialize};
+use yew::ServerRenderer;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Routable, Debug, Clone, PartialEq)]
+#[loader]
+enum Route {
+    #[at("/")]
+    Home,
+    #[at("/posts/{id}")]
+    Post { id: u32 },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Page {
+    title: String,
+}
+
+impl RouteLoader for Route {
+    type Data = Page;
+
+    async fn load(self) -> Page {
+        yew::platform::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        let title = match self {
+            Route::Home => "Home".to_owned(),
+            Route::Post { id } => format!("Post {id}"),
+        };
+
+        Page { title }
+    }
+}
+
+#[component]
+fn PageView() -> HtmlResult {
+    let page = use_loader_data::<Route>()?.unwrap();
+
+    Ok(html! { <h1>{ &page.title }</h1> })
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    history: AnyHistory,
+}
+
+#[component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <Router history={props.history.clone()}>
+            <Suspense fallback={html! { <p>{ "Loading" }</p> }}>
+                <Switch<Route> render={|_| html! { <PageView /> }} />
+            </Suspense>
+        </Router>
+    }
+}
+
+#[tokio::test]
+async fn loaded_data_is_rendered() {
+    assert!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/"])),
+        })
+        .hydratable(false)
+        .render()
+        .await
+        .starts_with("<h1>Home</h1>")
+    );
+    assert!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/posts/1"])),
+        })
+        .hydratable(false)
+        .render()
+        .await
+        .starts_with("<h1>Post 1</h1>")
+    );
+}
+
+#[tokio::test]
+async fn loaded_data_is_sent_with_the_page() {
+    let body = ServerRenderer::<App>::with_props(|| AppProps {
+        history: AnyHistory::from(MemoryHistory::with_entries(["/posts/2"])),
+    })
+    .hydratable(false)
+    .render()
+    .await;
+
+    assert!(
+        body.starts_with(r#"<h1>Post 2</h1><script type="application/x-yew-comp-state">"#),
+        "{body}"
+    );
+}
+
+#[component]
+fn Outside() -> HtmlResult {
+    let page = use_loader_data::<Route>()?;
+
+    Ok(html! { { page.is_none() } })
+}
+
+#[tokio::test]
+async fn no_data_outside_of_switch() {
+    let body = ServerRenderer::<Outside>::new()
+        .hydratable(false)
+        .render()
+        .await;
+
+    assert_eq!(body, "true");
+}
@@ -0,0 +1,79 @@
+#![cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+
+use yew::ServerRenderer;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Routable, Debug, Clone, PartialEq)]
+enum Route {
+    #[at("/")]
+    Home,
+    #[at("/posts")]
+    Posts,
+    #[at("/posts/{id}")]
+    Post { id: u32 },
+    #[at("/postscript")]
+    Postscript,
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    history: AnyHistory,
+}
+
+#[component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <Router history={props.history.clone()} basename="/base">
+            <NavLink<Route> to={Route::Home}>{"Home"}</NavLink<Route>>
+            <NavLink<Route> to={Route::Posts} classes="nav" match_mode={NavLinkMatch::Prefix}>
+                {"Posts"}
+            </NavLink<Route>>
+            <NavLink<Route> to={Route::Post { id: 1 }} active_class="current">
+                {"First"}
+            </NavLink<Route>>
+        </Router>
+    }
+}
+
+#[tokio::test]
+async fn exact_match() {
+    assert_eq!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/base/"])),
+        })
+        .hydratable(false)
+        .render()
+        .await,
+        "<a href=\"/base/\" aria-current=\"page\" class=\"active\">Home</a><a \
+         href=\"/base/posts\" class=\"nav\">Posts</a><a href=\"/base/posts/1\">First</a>"
+    );
+}
+
+#[tokio::test]
+async fn prefix_match() {
+    assert_eq!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/base/posts/1"])),
+        })
+        .hydratable(false)
+        .render()
+        .await,
+        "<a href=\"/base/\">Home</a><a href=\"/base/posts\" aria-current=\"page\" class=\"nav \
+         active\">Posts</a><a href=\"/base/posts/1\" aria-current=\"page\" \
+         class=\"current\">First</a>"
+    );
+
+    // Only whole segments are matched.
+    assert_eq!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/base/postscript"])),
+        })
+        .hydratable(false)
+        .render()
+        .await,
+        "<a href=\"/base/\">Home</a><a href=\"/base/posts\" class=\"nav\">Posts</a><a \
+         href=\"/base/posts/1\">First</a>"
+    );
+}
@@ -0,0 +1,158 @@
+#![cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+
+use yew::ServerRenderer;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Routable, Debug, Clone, PartialEq)]
+enum Route {
+    #[at("/")]
+    Home,
+    #[nested]
+    #[at("/settings/*")]
+    Settings(SettingsRoute),
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+#[derive(Routable, Debug, Clone, PartialEq)]
+enum SettingsRoute {
+    #[at("/")]
+    Profile,
+    #[at("/keys/{id}")]
+    Key { id: u32 },
+    #[nested]
+    #[at("/team")]
+    Team(TeamRoute),
+}
+
+#[derive(Routable, Debug, Clone, PartialEq)]
+enum TeamRoute {
+    #[at("/members")]
+    Members,
+}
+
+#[test]
+fn nested_routes_are_recognised() {
+    assert_eq!(
+        Route::recognize("/settings"),
+        Some(Route::Settings(SettingsRoute::Profile))
+    );
+    assert_eq!(
+        Route::recognize("/settings/"),
+        Some(Route::Settings(SettingsRoute::Profile))
+    );
+    assert_eq!(
+        Route::recognize("/settings/keys/3"),
+        Some(Route::Settings(SettingsRoute::Key { id: 3 }))
+    );
+    assert_eq!(
+        Route::recognize("/settings/team/members"),
+        Some(Route::Settings(SettingsRoute::Team(TeamRoute::Members)))
+    );
+    assert_eq!(Route::recognize("/settings/keys/a"), Some(Route::NotFound));
+    assert_eq!(Route::recognize("/settingsx"), Some(Route::NotFound));
+}
+
+#[test]
+fn nested_routes_are_prefixed() {
+    assert_eq!(
+        Route::Settings(SettingsRoute::Profile).to_path(),
+        "/settings"
+    );
+    assert_eq!(
+        Route::Settings(SettingsRoute::Key { id: 3 }).to_path(),
+        "/settings/keys/3"
+    );
+    assert_eq!(
+        Route::Settings(SettingsRoute::Team(TeamRoute::Members)).to_path(),
+        "/settings/team/members"
+    );
+    assert_eq!(
+        Route::Settings(SettingsRoute::Key { id: 3 }).nested_path(),
+        Some("/keys/3".to_owned())
+    );
+    assert_eq!(Route::Home.nested_path(), None);
+
+    assert_eq!(
+        Route::routes(),
+        vec![
+            "/",
+            "/settings",
+            "/settings/",
+            "/settings/keys/{id}",
+            "/settings/team/members",
+            "/404",
+        ]
+    );
+}
+
+fn switch(route: Route) -> Html {
+    match route {
+        Route::Home => html! { <h1>{"Home"}</h1> },
+        Route::Settings(_) => html! {
+            <section>
+                <h1>{"Settings"}</h1>
+                <Outlet<SettingsRoute> render={switch_settings} />
+            </section>
+        },
+        Route::NotFound => html! { <h1>{"Not Found"}</h1> },
+    }
+}
+
+fn switch_settings(route: SettingsRoute) -> Html {
+    match route {
+        SettingsRoute::Profile => html! { <p>{"Profile"}</p> },
+        SettingsRoute::Key { id } => html! { <p>{format!("Key {id}")}</p> },
+        SettingsRoute::Team(_) => html! {
+            <Outlet<TeamRoute> render={|_| html! { <p>{"Members"}</p> }} />
+        },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    history: AnyHistory,
+}
+
+#[component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <Router history={props.history.clone()}>
+            <Switch<Route> render={switch} />
+        </Router>
+    }
+}
+
+#[tokio::test]
+async fn outlet_renders_nested_route() {
+    assert_eq!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/settings"])),
+        })
+        .hydratable(false)
+        .render()
+        .await,
+        "<section><h1>Settings</h1><p>Profile</p></section>"
+    );
+    assert_eq!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/settings/keys/7"])),
+        })
+        .hydratable(false)
+        .render()
+        .await,
+        "<section><h1>Settings</h1><p>Key 7</p></section>"
+    );
+    assert_eq!(
+        ServerRenderer::<App>::with_props(|| AppProps {
+            history: AnyHistory::from(MemoryHistory::with_entries(["/settings/team/members"])),
+        })
+        .hydratable(false)
+        .render()
+        .await,
+        "<section><h1>Settings</h1><p>Members</p></section>"
+    );
+}
@@ -0,0 +1,111 @@
+#![cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+
+use std::collections::HashMap;
+use std::fs;
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yew_router::ssg::StaticSiteGenerator;
+
+#[derive(Routable, Debug, Clone, PartialEq)]
+enum Route {
+    #[at("/")]
+    Home,
+    #[at("/posts/{id}")]
+    Post { id: u32 },
+    #[at("/tags/{tag}")]
+    Tag { tag: String },
+    #[at("/files/{*path}")]
+    File { path: String },
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+#[component]
+fn Content() -> Html {
+    html! {
+        <Switch<Route> render={|route| html! { <p>{format!("{route:?}")}</p> }} />
+    }
+}
+
+fn generator(out_dir: &std::path::Path) -> StaticSiteGenerator<Route, Content> {
+    StaticSiteGenerator::new(out_dir)
+        .hydratable(false)
+        .params(|pattern| match pattern {
+            "/posts/{id}" => (1..=2)
+                .map(|id| HashMap::from([("id".to_owned(), id.to_string())]))
+                .collect(),
+            "/tags/{tag}" => vec![HashMap::from([("tag".to_owned(), "rust & yew".to_owned())])],
+            "/files/{*path}" => vec![HashMap::from([(
+                "path".to_owned(),
+                "docs/read me".to_owned(),
+            )])],
+            _ => Vec::new(),
+        })
+}
+
+#[test]
+fn pages_of_routes() {
+    let pages = generator("out".as_ref()).pages().unwrap();
+
+    assert_eq!(
+        pages,
+        vec![
+            (Route::Home, "/".to_owned()),
+            (Route::Post { id: 1 }, "/posts/1".to_owned()),
+            (Route::Post { id: 2 }, "/posts/2".to_owned()),
+            (
+                Route::Tag {
+                    tag: "rust & yew".to_owned()
+                },
+                "/tags/rust%20%26%20yew".to_owned()
+            ),
+            (
+                Route::File {
+                    path: "docs/read me".to_owned()
+                },
+                "/files/docs/read%20me".to_owned()
+            ),
+            (Route::NotFound, "/404".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn missing_params_are_rejected() {
+    let pages = StaticSiteGenerator::<Route, Content>::new("out")
+        .params(|_| vec![HashMap::new()])
+        .pages();
+
+    assert!(pages.is_err());
+}
+
+#[tokio::test]
+async fn generate_writes_index_files() {
+    let out_dir = std::env::temp_dir().join(format!("yew-router-ssg-{}", std::process::id()));
+
+    let generated = generator(&out_dir)
+        .template(r#"<html><body class="page"><script></script></body></html>"#)
+        .generate()
+        .await
+        .unwrap();
+    assert_eq!(generated.len(), 6);
+
+    assert_eq!(
+        fs::read_to_string(out_dir.join("index.html")).unwrap(),
+        r#"<html><body class="page"><p>Home</p><script></script></body></html>"#
+    );
+    assert_eq!(
+        fs::read_to_string(out_dir.join("posts/2/index.html")).unwrap(),
+        r#"<html><body class="page"><p>Post { id: 2 }</p><script></script></body></html>"#
+    );
+    assert_eq!(
+        fs::read_to_string(out_dir.join("tags/rust & yew/index.html")).unwrap(),
+        r#"<html><body class="page"><p>Tag { tag: "rust &amp; yew" }</p><script></script></body></html>"#
+    );
+    assert!(out_dir.join("files/docs/read me/index.html").exists());
+    assert_eq!(generated[1].file, out_dir.join("posts/1/index.html"));
+
+    fs::remove_dir_all(out_dir).unwrap();
+}
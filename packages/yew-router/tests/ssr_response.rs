@@ -0,0 +1,82 @@
+#![cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+
+use yew::ServerRenderer;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Routable, Debug, Clone, PartialEq)]
+enum Route {
+    #[at("/")]
+    Home,
+    #[at("/old")]
+    Old,
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+fn switch(route: Route) -> Html {
+    match route {
+        Route::Home => html! { <h1>{"Home"}</h1> },
+        Route::Old => html! { <Redirect<Route> to={Route::Home} /> },
+        Route::NotFound => html! { <h1>{"Not Found"}</h1> },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    history: AnyHistory,
+}
+
+#[component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <Router history={props.history.clone()} basename="/base">
+            <Switch<Route> render={switch} />
+        </Router>
+    }
+}
+
+#[tokio::test]
+async fn matched_route_is_ok() {
+    let response = ServerRenderer::<App>::with_props(|| AppProps {
+        history: AnyHistory::from(MemoryHistory::with_entries(["/base/"])),
+    })
+    .hydratable(false)
+    .render_response()
+    .await;
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, "<h1>Home</h1>");
+}
+
+#[tokio::test]
+async fn redirect_sets_location() {
+    let response = ServerRenderer::<App>::with_props(|| AppProps {
+        history: AnyHistory::from(MemoryHistory::with_entries(["/base/old"])),
+    })
+    .hydratable(false)
+    .render_response()
+    .await;
+
+    assert_eq!(response.status, 307);
+    assert_eq!(
+        response.headers,
+        vec![("Location".to_owned(), "/base/".to_owned())]
+    );
+    assert_eq!(response.body, "");
+}
+
+#[tokio::test]
+async fn not_found_route_is_404() {
+    let response = ServerRenderer::<App>::with_props(|| AppProps {
+        history: AnyHistory::from(MemoryHistory::with_entries(["/base/missing"])),
+    })
+    .hydratable(false)
+    .render_response()
+    .await;
+
+    assert_eq!(response.status, 404);
+    assert_eq!(response.body, "<h1>Not Found</h1>");
+}
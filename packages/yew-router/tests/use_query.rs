@@ -0,0 +1,109 @@
+#![cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+
+use std::cell::RefCell;
+
+use serde::{Deserialize, Serialize};
+use yew::LocalServerRenderer;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::hooks::QuerySetter;
+use yew_router::prelude::*;
+use yew_router::query::Raw;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Search {
+    q: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ScrollPosition(u32);
+
+thread_local! {
+    static SETTER: RefCell<Option<QuerySetter<Search>>> = const { RefCell::new(None) };
+}
+
+#[component]
+fn SearchPage() -> Html {
+    let (search, set_search) = use_query::<Search>().unwrap();
+    SETTER.with(|m| *m.borrow_mut() = Some(set_search));
+
+    match search {
+        Ok(search) => html! { <p>{search.q}</p> },
+        Err(_) => html! { <p>{"invalid query"}</p> },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    history: AnyHistory,
+}
+
+#[component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <Router history={props.history.clone()} basename="/base">
+            <SearchPage />
+        </Router>
+    }
+}
+
+async fn render(history: &AnyHistory) -> String {
+    LocalServerRenderer::<App>::with_props(AppProps {
+        history: history.clone(),
+    })
+    .hydratable(false)
+    .render()
+    .await
+}
+
+fn history_with_query(query: &str) -> AnyHistory {
+    let history = AnyHistory::from(MemoryHistory::new());
+    history
+        .push_with_query_and_state("/base/search", Raw(query), ScrollPosition(10))
+        .unwrap();
+    history
+}
+
+#[tokio::test]
+async fn query_is_decoded() {
+    assert_eq!(render(&history_with_query("q=yew")).await, "<p>yew</p>");
+    assert_eq!(
+        render(&history_with_query("p=yew")).await,
+        "<p>invalid query</p>"
+    );
+}
+
+#[tokio::test]
+async fn setter_keeps_path_and_state() {
+    let history = history_with_query("q=yew");
+    render(&history).await;
+    let setter = SETTER.with(|m| m.borrow_mut().take()).unwrap();
+
+    setter
+        .clone()
+        .with_state::<ScrollPosition>()
+        .replace(&Search {
+            q: "router".to_owned(),
+        })
+        .unwrap();
+    let location = history.location();
+    assert_eq!(location.path(), "/base/search");
+    assert_eq!(location.query_str(), "?q=router");
+    assert_eq!(
+        location.state::<ScrollPosition>().as_deref(),
+        Some(&ScrollPosition(10))
+    );
+    assert_eq!(history.len(), 2);
+
+    // The state is only kept by a setter that knows its type.
+    setter
+        .push(&Search {
+            q: "hooks".to_owned(),
+        })
+        .unwrap();
+    let location = history.location();
+    assert_eq!(location.path(), "/base/search");
+    assert_eq!(location.query_str(), "?q=hooks");
+    assert_eq!(location.state::<ScrollPosition>(), None);
+    assert_eq!(history.len(), 3);
+}
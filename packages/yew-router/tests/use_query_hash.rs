@@ -0,0 +1,80 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{BrowserHistory, History};
+use yew_router::prelude::*;
+use yew_router::query::Raw;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Serialize, Deserialize)]
+struct Search {
+    q: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ScrollPosition(u32);
+
+#[component]
+fn SearchPage() -> Html {
+    let (search, set_search) = use_query::<Search>().unwrap();
+    let set_search = set_search.with_state::<ScrollPosition>();
+    let onclick = move |_| {
+        set_search
+            .push(&Search {
+                q: "router".to_owned(),
+            })
+            .unwrap();
+    };
+
+    html! {
+        <div id="result">{search.unwrap().q}</div>
+        <button {onclick}>{"search"}</button>
+    }
+}
+
+#[component]
+fn Root() -> Html {
+    html! {
+        <BrowserRouter>
+            <SearchPage />
+        </BrowserRouter>
+    }
+}
+
+#[test]
+async fn setter_keeps_hash_and_state() {
+    let history = BrowserHistory::new();
+    history
+        .push_with_query_and_state("/search#results", Raw("q=yew"), ScrollPosition(10))
+        .unwrap();
+
+    yew::Renderer::<Root>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!("yew", obtain_result_by_id("result"));
+
+    click("button");
+
+    sleep(Duration::ZERO).await;
+    assert_eq!("router", obtain_result_by_id("result"));
+
+    let location = history.location();
+    assert_eq!(location.path(), "/search");
+    assert_eq!(location.query_str(), "?q=router");
+    assert_eq!(location.hash(), "#results");
+    assert_eq!(
+        location.state::<ScrollPosition>().as_deref(),
+        Some(&ScrollPosition(10))
+    );
+}
@@ -62,6 +62,8 @@ where
         skip_all,
     )]
     pub fn destroy(self) {
+        #[cfg(feature = "hydration")]
+        crate::functional::StateCodec::unregister(self.scope.id);
         self.scope.destroy(false)
     }
 }
@@ -87,7 +89,8 @@ fn clear_element(host: &Element) {
 #[cfg(feature = "hydration")]
 mod feat_hydration {
     use super::*;
-    use crate::dom_bundle::Fragment;
+    use crate::dom_bundle::{Fragment, mismatch};
+    use crate::functional::StateCodec;
 
     impl<COMP> AppHandle<COMP>
     where
@@ -98,10 +101,15 @@ mod feat_hydration {
             name = "hydrate",
             skip(props),
         )]
-        pub(crate) fn hydrate_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
+        pub(crate) fn hydrate_with_props(
+            host: Element,
+            props: Rc<COMP::Properties>,
+            state_codec: StateCodec,
+        ) -> Self {
             let app = Self {
                 scope: Scope::new(None),
             };
+            state_codec.register(app.scope.id);
 
             let mut fragment = Fragment::collect_children(&host);
             let hosting_root = BSubtree::create_root(&host);
@@ -117,6 +125,9 @@ mod feat_hydration {
             if let Some(previous_next_sibling) = previous_next_sibling {
                 previous_next_sibling.reassign(DomSlot::at_end());
             }
+            if let Some(diff) = mismatch::take() {
+                diff.report(&app.scope.clone().into());
+            }
 
             // We remove all remaining nodes, this mimics the clear_element behaviour in
             // mount_with_props.
@@ -267,7 +267,7 @@ impl fmt::Debug for BNode {
 #[cfg(feature = "hydration")]
 mod feat_hydration {
     use super::*;
-    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable};
+    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable, mismatch};
 
     impl Hydratable for VNode {
         fn hydrate(
@@ -278,6 +278,18 @@ mod feat_hydration {
             fragment: &mut Fragment,
             prev_next_sibling: &mut Option<DynamicDomSlot>,
         ) -> Self::Bundle {
+            // The rest of a component that failed to hydrate is rendered on the client.
+            if mismatch::is_recovering() {
+                return mismatch::attach_instead(
+                    self,
+                    root,
+                    parent_scope,
+                    parent,
+                    fragment,
+                    prev_next_sibling,
+                );
+            }
+
             match self {
                 VNode::VTag(vtag) => RcExt::unwrap_or_clone(vtag)
                     .hydrate(root, parent_scope, parent, fragment, prev_next_sibling)
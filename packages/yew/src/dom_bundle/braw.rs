@@ -146,20 +146,30 @@ impl Reconcilable for VRaw {
 #[cfg(feature = "hydration")]
 mod feat_hydration {
     use super::*;
-    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable};
+    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable, mismatch};
     use crate::virtual_dom::Collectable;
 
     impl Hydratable for VRaw {
         fn hydrate(
             self,
-            _root: &BSubtree,
-            _parent_scope: &AnyScope,
+            root: &BSubtree,
+            parent_scope: &AnyScope,
             parent: &Element,
             fragment: &mut Fragment,
             prev_next_sibling: &mut Option<DynamicDomSlot>,
         ) -> Self::Bundle {
             let collectable = Collectable::Raw;
-            let fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
+            let Some(fallback_fragment) = Fragment::collect_between(fragment, &collectable, parent)
+            else {
+                return mismatch::attach_instead(
+                    self,
+                    root,
+                    parent_scope,
+                    parent,
+                    fragment,
+                    prev_next_sibling,
+                );
+            };
             let first_child = fallback_fragment.iter().next().cloned();
 
             if let (Some(first_child), prev_next_sibling) = (&first_child, prev_next_sibling) {
@@ -223,10 +223,43 @@ impl Reconcilable for VSuspense {
 
 #[cfg(feature = "hydration")]
 mod feat_hydration {
+    use wasm_bindgen::JsCast;
+    use web_sys::{HtmlTemplateElement, Node};
+
     use super::*;
-    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable};
+    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable, mismatch};
     use crate::virtual_dom::Collectable;
 
+    /// Returns the start marker of a suspense streamed out of order whose fallback is still in
+    /// place, along with the id of the boundary.
+    fn pending_boundary(fragment: &Fragment) -> Option<(Element, String)> {
+        let start = fragment.front()?.dyn_ref::<HtmlTemplateElement>()?;
+        let id = start
+            .id()
+            .strip_prefix(Collectable::SUSPENSE_START_ID_PREFIX)?
+            .to_owned();
+
+        Some((start.clone().into(), id))
+    }
+
+    /// Replaces the fallback of a suspense streamed out of order with its content.
+    ///
+    /// This is done by an inline script streamed alongside the content, but that script does not
+    /// run if the markup is inserted with `innerHTML`.
+    fn swap_content(fragment: &mut Fragment, parent: &Element, content: HtmlTemplateElement) {
+        let next_sibling = fragment.sibling_at_end().cloned();
+        let content = content.content();
+
+        for node in fragment.drain(..) {
+            parent.remove_child(&node).unwrap();
+        }
+
+        while let Some(node) = content.first_child() {
+            parent.insert_before(&node, next_sibling.as_ref()).unwrap();
+            fragment.push_back(node);
+        }
+    }
+
     impl Hydratable for VSuspense {
         fn hydrate(
             self,
@@ -241,7 +274,52 @@ mod feat_hydration {
                 .expect("failed to create detached element");
 
             let collectable = Collectable::Suspense;
-            let fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
+            let Some(mut fallback_fragment) =
+                Fragment::collect_between(fragment, &collectable, parent)
+            else {
+                return mismatch::attach_instead(
+                    self,
+                    root,
+                    parent_scope,
+                    parent,
+                    fragment,
+                    previous_next_sibling,
+                );
+            };
+
+            if let Some((start, id)) = pending_boundary(&fallback_fragment) {
+                let content = document()
+                    .get_element_by_id(&format!("{}{id}", Collectable::SUSPENSE_CONTENT_ID_PREFIX))
+                    .and_then(|m| m.dyn_into::<HtmlTemplateElement>().ok());
+
+                match content {
+                    Some(content) => swap_content(&mut fallback_fragment, parent, content),
+                    None => {
+                        // The content has not been streamed yet, so there is nothing to hydrate.
+                        // We render the children while the fallback sent by the server is shown
+                        // and prevent the content from being swapped in once it arrives.
+                        start.remove_attribute("id").unwrap();
+
+                        let (_, children_bundle) = self.children.attach(
+                            root,
+                            parent_scope,
+                            &detached_parent,
+                            DomSlot::at_end(),
+                        );
+
+                        if let Some(previous_next_sibling) = previous_next_sibling.take() {
+                            previous_next_sibling.reassign(DomSlot::at(Node::from(start)));
+                        }
+
+                        return BSuspense {
+                            children_bundle,
+                            detached_parent,
+                            key: self.key,
+                            fallback: Some(Fallback::Fragment(fallback_fragment)),
+                        };
+                    }
+                }
+            }
 
             let mut nodes = fallback_fragment.deep_clone();
 
@@ -262,7 +340,9 @@ mod feat_hydration {
             // We trim all leading text nodes before checking as it's likely these are whitespaces.
             nodes.trim_start_text_nodes();
 
-            assert!(nodes.is_empty(), "expected end of suspense, found node.");
+            if !nodes.is_empty() {
+                mismatch::record("end of suspense", nodes.front());
+            }
 
             BSuspense {
                 children_bundle,
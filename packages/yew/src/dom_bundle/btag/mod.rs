@@ -338,48 +338,25 @@ impl BTag {
 
 #[cfg(feature = "hydration")]
 mod feat_hydration {
-    use web_sys::Node;
-
     use super::*;
-    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable, node_type_str};
+    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable, mismatch};
 
     impl Hydratable for VTag {
         fn hydrate(
             self,
             root: &BSubtree,
             parent_scope: &AnyScope,
-            _parent: &Element,
+            parent: &Element,
             fragment: &mut Fragment,
             prev_next_sibling: &mut Option<DynamicDomSlot>,
         ) -> Self::Bundle {
-            let tag_name = self.tag().to_owned();
-
-            let Self {
-                inner,
-                listeners,
-                attributes,
-                node_ref,
-                key,
-            } = self;
-
             // We trim all text nodes as it's likely these are whitespaces.
             fragment.trim_start_text_nodes();
 
-            let node = fragment
-                .pop_front()
-                .unwrap_or_else(|| panic!("expected element of type {tag_name}, found EOF."));
-
-            assert_eq!(
-                node.node_type(),
-                Node::ELEMENT_NODE,
-                "expected element, found node type {}.",
-                node_type_str(&node),
-            );
-            let el = node.dyn_into::<Element>().expect("expected an element.");
-
-            {
+            let matches_tag = |el: &Element| {
+                let tag_name = self.tag();
                 let el_tag_name = el.tag_name();
-                let parent_namespace = _parent.namespace_uri();
+                let parent_namespace = parent.namespace_uri();
 
                 // In HTML namespace (or no namespace), createElement is case-insensitive
                 // In other namespaces (SVG, MathML), createElementNS is case-sensitive
@@ -389,18 +366,37 @@ mod feat_hydration {
 
                 if should_compare_case_insensitive {
                     // Case-insensitive comparison for HTML elements
-                    assert!(
-                        tag_name.eq_ignore_ascii_case(&el_tag_name),
-                        "expected element of kind {tag_name}, found {el_tag_name}.",
-                    );
+                    tag_name.eq_ignore_ascii_case(&el_tag_name)
                 } else {
                     // Case-sensitive comparison for namespaced elements (SVG, MathML)
-                    assert_eq!(
-                        el_tag_name, tag_name,
-                        "expected element of kind {tag_name}, found {el_tag_name}.",
+                    el_tag_name == tag_name
+                }
+            };
+
+            let el = match fragment.front().and_then(|m| m.dyn_ref::<Element>()) {
+                Some(el) if matches_tag(el) => el.clone(),
+                _ => {
+                    mismatch::record(format!("{} element node", self.tag()), fragment.front());
+                    return mismatch::attach_instead(
+                        self,
+                        root,
+                        parent_scope,
+                        parent,
+                        fragment,
+                        prev_next_sibling,
                     );
                 }
-            }
+            };
+            fragment.pop_front();
+
+            let Self {
+                inner,
+                listeners,
+                attributes,
+                node_ref,
+                key,
+            } = self;
+
             // We simply register listeners and update all attributes.
             let attributes = attributes.apply(root, &el);
             let listeners = listeners.apply(root, &el);
@@ -427,7 +423,9 @@ mod feat_hydration {
 
                     nodes.trim_start_text_nodes();
 
-                    assert!(nodes.is_empty(), "expected EOF, found node.");
+                    if !nodes.is_empty() {
+                        mismatch::record(format!("end of {tag} element"), nodes.front());
+                    }
 
                     BTagInner::Other { child_bundle, tag }
                 }
@@ -4,7 +4,7 @@ use std::ops::{Deref, DerefMut};
 use wasm_bindgen::JsCast;
 use web_sys::{Element, Node};
 
-use super::{BSubtree, DomSlot};
+use super::{BSubtree, DomSlot, mismatch};
 use crate::virtual_dom::Collectable;
 
 /// A Hydration Fragment
@@ -43,11 +43,14 @@ impl Fragment {
     }
 
     /// Collects nodes for a Component Bundle or a BSuspense.
+    ///
+    /// Returns `None` and records a hydration mismatch if the nodes are not enclosed by the
+    /// expected opening and closing tags.
     pub fn collect_between(
         collect_from: &mut Fragment,
         collect_for: &Collectable,
         parent: &Element,
-    ) -> Self {
+    ) -> Option<Self> {
         let is_open_tag = |node: &Node| {
             let comment_text = node.text_content().unwrap_or_default();
 
@@ -65,37 +68,28 @@ impl Fragment {
         // We trim all leading text nodes as it's likely these are whitespaces.
         collect_from.trim_start_text_nodes();
 
-        let first_node = collect_from
-            .pop_front()
-            .unwrap_or_else(|| panic!("expected {} opening tag, found EOF", collect_for.name()));
-
-        assert_eq!(
-            first_node.node_type(),
-            Node::COMMENT_NODE,
-            // TODO: improve error message with human readable node type name.
-            "expected {} start, found node type {}",
-            collect_for.name(),
-            first_node.node_type()
-        );
-
-        let mut nodes = VecDeque::new();
-
-        if !is_open_tag(&first_node) {
-            panic!(
-                "expected {} opening tag, found comment node",
-                collect_for.name()
-            );
+        match collect_from.front() {
+            Some(m) if m.node_type() == Node::COMMENT_NODE && is_open_tag(m) => {}
+            found => {
+                mismatch::record(format!("{} opening tag", collect_for.name()), found);
+                return None;
+            }
         }
 
         // We remove the opening tag.
+        let first_node = collect_from.pop_front().unwrap();
         parent.remove_child(&first_node).unwrap();
 
+        let mut nodes = VecDeque::new();
         let mut nested_layers = 1;
 
         loop {
-            let current_node = collect_from.pop_front().unwrap_or_else(|| {
-                panic!("expected {} closing tag, found EOF", collect_for.name())
-            });
+            let Some(current_node) = collect_from.pop_front() else {
+                mismatch::record(format!("{} closing tag", collect_for.name()), None);
+                // The collected nodes are left to be discarded with the rest of the markup.
+                collect_from.0 = nodes;
+                return None;
+            };
 
             if current_node.node_type() == Node::COMMENT_NODE {
                 if is_open_tag(&current_node) {
@@ -119,7 +113,7 @@ impl Fragment {
         }
 
         let next_child = collect_from.0.front().cloned();
-        Self(nodes, next_child)
+        Some(Self(nodes, next_child))
     }
 
     /// Remove child nodes until first non-text node.
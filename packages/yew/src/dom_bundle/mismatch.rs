@@ -0,0 +1,178 @@
+//! Recovery from hydration mismatches.
+//!
+//! When the markup rendered by the server does not match the layout rendered on the client, the
+//! mismatch is recorded instead of panicking. Everything that remains to be hydrated in the
+//! current component is client-rendered, and once the component has been hydrated, it discards
+//! its bundle and the remaining server markup and renders again from scratch.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use web_sys::{Element, Node};
+
+use super::{BSubtree, DomSlot, DynamicDomSlot, Fragment, Hydratable, node_type_str};
+use crate::html::AnyScope;
+
+type MismatchHook = Rc<dyn Fn(&HydrationMismatch)>;
+
+thread_local! {
+    static HOOK: RefCell<Option<MismatchHook>> = const { RefCell::new(None) };
+    /// The first mismatch found while hydrating the current component.
+    static PENDING: RefCell<Option<Diff>> = const { RefCell::new(None) };
+}
+
+/// Sets the hook called when the markup rendered by the server does not match the layout
+/// rendered by the client during hydration.
+///
+/// The component where the mismatch was found is rendered again on the client. Unless a hook is
+/// set through this function, mismatches are logged to the console as warnings.
+pub fn set_hydration_mismatch_hook<F>(hook: F)
+where
+    F: Fn(&HydrationMismatch) + 'static,
+{
+    HOOK.with(|m| *m.borrow_mut() = Some(Rc::new(hook)));
+}
+
+/// A difference between the markup rendered by the server and the layout rendered by the client,
+/// found during hydration.
+///
+/// See [`set_hydration_mismatch_hook`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HydrationMismatch {
+    component_path: Vec<&'static str>,
+    expected: String,
+    found: String,
+}
+
+impl HydrationMismatch {
+    /// Returns the type name of the component that is rendered again on the client.
+    pub fn component(&self) -> &'static str {
+        self.component_path
+            .last()
+            .copied()
+            .expect("a mismatch is attributed to a component")
+    }
+
+    /// Returns the type names of the components from the root of the application to the
+    /// component that is rendered again on the client.
+    pub fn component_path(&self) -> &[&'static str] {
+        &self.component_path
+    }
+
+    /// Returns a description of the node expected by the client, including its tag name.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Returns a description of the node found in the markup rendered by the server, including
+    /// its tag name or text.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl fmt::Display for HydrationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "hydration mismatch in {}, rendering it on the client instead:",
+            self.component_path.join(" > ")
+        )?;
+        writeln!(f, "- expected {}", self.expected)?;
+        write!(f, "+ found {}", self.found)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Diff {
+    expected: String,
+    found: String,
+}
+
+impl Diff {
+    /// Reports the mismatch, attributing it to the component of `scope`.
+    pub fn report(self, scope: &AnyScope) {
+        let mut component_path = vec![scope.type_name];
+        let mut parent = scope.get_parent();
+        while let Some(m) = parent {
+            component_path.push(m.type_name);
+            parent = m.get_parent();
+        }
+        component_path.reverse();
+
+        let mismatch = HydrationMismatch {
+            component_path,
+            expected: self.expected,
+            found: self.found,
+        };
+
+        match HOOK.with(|m| m.borrow().clone()) {
+            Some(hook) => hook(&mismatch),
+            None => gloo::console::warn!(mismatch.to_string()),
+        }
+    }
+}
+
+/// Records a mismatch in the current component.
+///
+/// Only the first mismatch is kept, as later ones are likely caused by it.
+pub(crate) fn record(expected: impl Into<String>, found: Option<&Node>) {
+    let found = match found {
+        Some(m) if matches!(m.node_type(), Node::TEXT_NODE | Node::COMMENT_NODE) => format!(
+            "{} {:?}",
+            node_type_str(m),
+            m.text_content().unwrap_or_default()
+        ),
+        Some(m) => node_type_str(m).into_owned(),
+        None => "end of content".to_owned(),
+    };
+
+    PENDING.with(|m| {
+        m.borrow_mut().get_or_insert(Diff {
+            expected: expected.into(),
+            found,
+        });
+    });
+}
+
+/// Returns whether a mismatch has been found in the current component.
+pub(in crate::dom_bundle) fn is_recovering() -> bool {
+    PENDING.with(|m| m.borrow().is_some())
+}
+
+/// Takes the mismatch found in the current component, if any.
+pub(crate) fn take() -> Option<Diff> {
+    PENDING.with(|m| m.borrow_mut().take())
+}
+
+/// Renders `node` on the client in place of the remaining server markup.
+///
+/// The created bundle is discarded together with the rest of the component once its hydration
+/// has finished.
+pub(in crate::dom_bundle) fn attach_instead<T>(
+    node: T,
+    root: &BSubtree,
+    parent_scope: &AnyScope,
+    parent: &Element,
+    fragment: &Fragment,
+    prev_next_sibling: &mut Option<DynamicDomSlot>,
+) -> T::Bundle
+where
+    T: Hydratable,
+{
+    let slot = DomSlot::create(
+        fragment
+            .front()
+            .or_else(|| fragment.sibling_at_end())
+            .cloned(),
+    );
+    let (slot, bundle) = node.attach(root, parent_scope, parent, slot);
+
+    if let Some(prev_next_sibling) = prev_next_sibling {
+        prev_next_sibling.reassign(slot);
+    }
+    *prev_next_sibling = None;
+
+    bundle
+}
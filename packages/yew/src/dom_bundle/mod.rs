@@ -83,7 +83,10 @@ mod feat_hydration {
     pub(super) use super::utils::node_type_str;
     #[path = "./fragment.rs"]
     mod fragment;
+    #[path = "./mismatch.rs"]
+    pub(crate) mod mismatch;
     pub(crate) use fragment::Fragment;
+    pub use mismatch::{HydrationMismatch, set_hydration_mismatch_hook};
 
     use super::*;
     impl Bundle {
@@ -103,3 +106,5 @@ mod feat_hydration {
 }
 #[cfg(feature = "hydration")]
 pub(crate) use feat_hydration::*;
+#[cfg(feature = "hydration")]
+pub use feat_hydration::{HydrationMismatch, set_hydration_mismatch_hook};
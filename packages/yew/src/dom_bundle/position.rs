@@ -190,6 +190,12 @@ impl DynamicDomSlot {
         }
     }
 
+    /// Get the [Node] that comes just after the position, or `None` if this denotes the position at
+    /// the end
+    pub fn next_sibling(&self) -> Option<Node> {
+        self.with_next_sibling(|n| n.cloned())
+    }
+
     fn with_next_sibling<R>(&self, f: impl FnOnce(Option<&Node>) -> R) -> R {
         // we use an iterative approach to traverse a possible long chain for references
         // see for example issue #3043 why a recursive call is impossible for large lists in vdom
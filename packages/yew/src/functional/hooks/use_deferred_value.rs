@@ -0,0 +1,118 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use super::{UseForceUpdateHandle, use_force_update, use_mut_ref};
+use crate::functional::hook;
+use crate::scheduler::{self, Runnable};
+
+struct DeferredState<T> {
+    deferred: T,
+    latest: T,
+    scheduled: bool,
+}
+
+/// Updates the deferred value to the latest one in a low priority pass.
+struct DeferredUpdate<T> {
+    state: Weak<RefCell<DeferredState<T>>>,
+    trigger: UseForceUpdateHandle,
+}
+
+impl<T> Runnable for DeferredUpdate<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn run(self: Box<Self>) {
+        let Some(state) = self.state.upgrade() else {
+            return;
+        };
+
+        let changed = {
+            let mut state = state.borrow_mut();
+            state.scheduled = false;
+
+            if state.deferred != state.latest {
+                state.deferred = state.latest.clone();
+                true
+            } else {
+                false
+            }
+        };
+
+        if changed {
+            self.trigger.force_update();
+        }
+    }
+}
+
+/// This hook returns a copy of `value` that lags behind it.
+///
+/// When `value` changes, the component is first rendered with the previous value. It is rendered
+/// again with the new value in a low priority pass, which runs once all other pending updates
+/// have been rendered. In the browser, this pass starts after the results of these updates have
+/// been painted.
+///
+/// This keeps urgent updates, such as typing into an input, responsive while an expensive part
+/// of the page catches up.
+///
+/// # Example
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #[derive(Properties, PartialEq)]
+/// struct ResultsProps {
+///     query: AttrValue,
+/// }
+///
+/// #[component]
+/// fn Results(props: &ResultsProps) -> Html {
+///     // An expensive list of results.
+///     # let _ = props;
+///     # Html::default()
+/// }
+///
+/// #[component]
+/// fn Search() -> Html {
+///     let query = use_state(AttrValue::default);
+///     let deferred_query = use_deferred_value((*query).clone());
+///
+///     let oninput = {
+///         let query = query.clone();
+///         Callback::from(move |e: InputEvent| {
+///             let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+///             query.set(input.value().into());
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <input value={(*query).clone()} {oninput} />
+///             <Results query={deferred_query} />
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_deferred_value<T>(value: T) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let state = use_mut_ref(|| DeferredState {
+        deferred: value.clone(),
+        latest: value.clone(),
+        scheduled: false,
+    });
+    let trigger = use_force_update();
+
+    let mut current = state.borrow_mut();
+    current.latest = value;
+
+    if current.deferred != current.latest && !current.scheduled {
+        current.scheduled = true;
+        scheduler::push_deferred(Box::new(DeferredUpdate {
+            state: Rc::downgrade(&state),
+            trigger,
+        }));
+    }
+
+    current.deferred.clone()
+}
@@ -0,0 +1,109 @@
+use crate::AttrValue;
+use crate::functional::{Hook, HookContext};
+
+/// This hook returns an id that is unique in the application.
+///
+/// The id is derived from the position of the component in the tree, so a component rendered by
+/// the server and the same component hydrating it on the client get the same id. This makes it
+/// suitable for pairs of attributes like `id` and `for` or `aria-describedby` in server-side
+/// rendered applications.
+///
+/// The id stays the same for the lifetime of the component. Ids are only unique within one
+/// application, multiple applications mounted in the same document may produce the same ids.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn EmailField() -> Html {
+///     let id = use_id();
+///     let hint_id = format!("{id}-hint");
+///
+///     html! {
+///         <div>
+///             <label for={id.clone()}>{"Email"}</label>
+///             <input {id} type="email" aria-describedby={hint_id.clone()} />
+///             <p id={hint_id}>{"We never share your email."}</p>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_id() -> impl Hook<Output = AttrValue> {
+    struct UseId;
+
+    impl Hook for UseId {
+        type Output = AttrValue;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let hook_pos = ctx.counter;
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            let position = ctx.scope.position.clone();
+
+            let id = ctx.next_state(move |_| {
+                let mut id = String::from("yew");
+
+                #[cfg(any(feature = "csr", feature = "ssr"))]
+                for index in position.path() {
+                    id.push_str(&format!("-{index}"));
+                }
+
+                id.push_str(&format!("-{hook_pos}"));
+                AttrValue::from(id)
+            });
+
+            (*id).clone()
+        }
+    }
+
+    UseId
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::prelude::*;
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_use_id_is_deterministic() {
+        #[component]
+        fn Field() -> Html {
+            let id = use_id();
+            let hint_id = use_id();
+
+            html! { <p {id} data-hint={hint_id} /> }
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            let id = use_id();
+
+            html! {
+                <div {id}>
+                    <Field />
+                    <Field />
+                </div>
+            }
+        }
+
+        let render = || ServerRenderer::<Comp>::new().hydratable(false).render();
+        let s = render().await;
+
+        assert_eq!(
+            s,
+            concat!(
+                r#"<div id="yew-0">"#,
+                r#"<p id="yew-0-0" data-hint="yew-0-1"></p>"#,
+                r#"<p id="yew-1-0" data-hint="yew-1-1"></p>"#,
+                "</div>",
+            )
+        );
+        assert_eq!(render().await, s);
+    }
+}
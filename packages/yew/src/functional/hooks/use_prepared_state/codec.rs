@@ -0,0 +1,378 @@
+//! The formats states prepared on the server side are sent to the client side with.
+
+use std::fmt;
+
+#[cfg(feature = "ssr")]
+use serde::Serialize;
+#[cfg(feature = "hydration")]
+use serde::de::DeserializeOwned;
+
+/// The format the states of [`use_prepared_state`](crate::functional::use_prepared_state) and
+/// [`use_transitive_state`](crate::functional::use_transitive_state) are embedded in the
+/// server-side rendering artifact with.
+///
+/// The codec is set on the server side with
+/// [`ServerRenderer::state_codec`](crate::ServerRenderer::state_codec) and on the client side with
+/// [`Renderer::state_codec`](crate::Renderer::state_codec). Both sides have to use the same
+/// codec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum StateCodec {
+    /// Compact binary encoding with [bincode](https://docs.rs/bincode), sent as base64.
+    #[default]
+    Bincode,
+    /// Human-readable encoding with [serde_json](https://docs.rs/serde_json).
+    ///
+    /// This requires the `json` feature.
+    #[cfg(feature = "json")]
+    Json,
+    /// Compact binary encoding with [postcard](https://docs.rs/postcard), sent as base64.
+    ///
+    /// This requires the `postcard` feature.
+    #[cfg(feature = "postcard")]
+    Postcard,
+}
+
+impl fmt::Display for StateCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bincode => f.write_str("bincode"),
+            #[cfg(feature = "json")]
+            Self::Json => f.write_str("JSON"),
+            #[cfg(feature = "postcard")]
+            Self::Postcard => f.write_str("postcard"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use base64ct::{Base64, Encoding};
+
+    use super::*;
+    use crate::html::AnyScope;
+
+    /// A state prepared on the server side that cannot be encoded.
+    #[derive(Debug)]
+    pub(crate) struct StateEncodeError {
+        codec: StateCodec,
+        reason: String,
+    }
+
+    impl StateEncodeError {
+        fn new(codec: StateCodec, reason: impl fmt::Display) -> Self {
+            Self {
+                codec,
+                reason: reason.to_string(),
+            }
+        }
+
+        /// Reports the error, attributing it to the component at `scope`.
+        ///
+        /// None of the states of the component are sent to the client, where the hooks behave
+        /// as if no state had been prepared.
+        pub fn report(&self, scope: &AnyScope) {
+            tracing::error!(
+                "failed to encode the state prepared for {} with {}: {}",
+                scope.type_name,
+                self.codec,
+                self.reason
+            );
+        }
+    }
+
+    impl fmt::Display for StateEncodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "failed to encode with {}: {}", self.codec, self.reason)
+        }
+    }
+
+    impl StateCodec {
+        /// Encodes a single prepared state.
+        pub(crate) fn encode<T>(self, value: &T) -> Result<String, StateEncodeError>
+        where
+            T: Serialize,
+        {
+            match self {
+                Self::Bincode => bincode::serde::encode_to_vec(value, bincode::config::standard())
+                    .map(|m| Base64::encode_string(&m))
+                    .map_err(|e| StateEncodeError::new(self, e)),
+                #[cfg(feature = "json")]
+                Self::Json => {
+                    serde_json::to_string(value).map_err(|e| StateEncodeError::new(self, e))
+                }
+                #[cfg(feature = "postcard")]
+                Self::Postcard => postcard::to_allocvec(value)
+                    .map(|m| Base64::encode_string(&m))
+                    .map_err(|e| StateEncodeError::new(self, e)),
+            }
+        }
+
+        /// Joins the states prepared by the hooks of a component.
+        ///
+        /// The result is written into a `<script>` element and must not close it.
+        pub(crate) fn join(self, states: Vec<String>) -> String {
+            match self {
+                Self::Bincode => states.join(","),
+                #[cfg(feature = "postcard")]
+                Self::Postcard => states.join(","),
+                // `<` can only appear in JSON strings, where it can be escaped.
+                #[cfg(feature = "json")]
+                Self::Json => format!("[{}]", states.join(",")).replace('<', r"\u003c"),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) use feat_ssr::StateEncodeError;
+
+#[cfg(feature = "hydration")]
+mod feat_hydration {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::iter;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::functional::decode_base64;
+    use crate::html::AnyScope;
+
+    thread_local! {
+        /// The codecs of the applications being hydrated, by the id of their root scope.
+        static CODECS: RefCell<HashMap<usize, StateCodec>> = RefCell::default();
+    }
+
+    /// A state prepared on the server side that cannot be decoded during hydration.
+    ///
+    /// This usually means the server and the client use different codecs, or different types
+    /// for the state or its dependencies.
+    #[derive(Debug)]
+    pub(crate) struct StateDecodeError {
+        codec: StateCodec,
+        reason: String,
+    }
+
+    impl StateDecodeError {
+        fn new(codec: StateCodec, reason: impl fmt::Display) -> Self {
+            Self {
+                codec,
+                reason: reason.to_string(),
+            }
+        }
+
+        /// Reports the error, attributing it to the component at `scope`.
+        ///
+        /// The hook behaves as if no state had been prepared.
+        pub fn report(&self, scope: &AnyScope) {
+            gloo::console::error!(format!(
+                "failed to decode the state prepared on the server side for {} with {}: {}",
+                scope.type_name, self.codec, self.reason
+            ));
+        }
+    }
+
+    impl fmt::Display for StateDecodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "failed to decode with {}: {}", self.codec, self.reason)
+        }
+    }
+
+    impl StateCodec {
+        /// Sets the codec of the application with the root scope `root_id`.
+        pub(crate) fn register(self, root_id: usize) {
+            if self != Self::default() {
+                CODECS.with(|m| m.borrow_mut().insert(root_id, self));
+            }
+        }
+
+        /// Removes the codec of the application with the root scope `root_id`.
+        pub(crate) fn unregister(root_id: usize) {
+            CODECS.with(|m| m.borrow_mut().remove(&root_id));
+        }
+
+        /// Finds the codec of the application the component at `scope` belongs to.
+        pub(crate) fn find(scope: &AnyScope) -> Self {
+            if CODECS.with(|m| m.borrow().is_empty()) {
+                return Self::default();
+            }
+
+            iter::successors(Some(scope), |m| m.get_parent())
+                .last()
+                .and_then(|root| CODECS.with(|m| m.borrow().get(&root.id).copied()))
+                .unwrap_or_default()
+        }
+
+        /// Splits the states prepared for the hooks of a component.
+        pub(crate) fn split(self, data: &str) -> Result<Vec<Rc<str>>, StateDecodeError> {
+            match self {
+                Self::Bincode => Ok(data.split(',').map(Rc::from).collect()),
+                #[cfg(feature = "postcard")]
+                Self::Postcard => Ok(data.split(',').map(Rc::from).collect()),
+                #[cfg(feature = "json")]
+                Self::Json => serde_json::from_str::<Vec<serde_json::Value>>(data)
+                    .map(|m| m.iter().map(|m| Rc::from(m.to_string())).collect())
+                    .map_err(|e| StateDecodeError::new(self, e)),
+            }
+        }
+
+        /// Decodes a single prepared state.
+        pub(crate) async fn decode<T>(self, data: &str) -> Result<T, StateDecodeError>
+        where
+            T: DeserializeOwned,
+        {
+            match self {
+                Self::Bincode => {
+                    let buf =
+                        decode_base64(&format!("data:application/octet-binary;base64,{data}"))
+                            .await
+                            .map_err(|e| StateDecodeError::new(self, format!("{e:?}")))?;
+
+                    bincode::serde::decode_from_slice(&buf, bincode::config::standard())
+                        .map(|(m, _)| m)
+                        .map_err(|e| StateDecodeError::new(self, e))
+                }
+                #[cfg(feature = "json")]
+                Self::Json => {
+                    serde_json::from_str(data).map_err(|e| StateDecodeError::new(self, e))
+                }
+                #[cfg(feature = "postcard")]
+                Self::Postcard => {
+                    let buf =
+                        decode_base64(&format!("data:application/octet-binary;base64,{data}"))
+                            .await
+                            .map_err(|e| StateDecodeError::new(self, format!("{e:?}")))?;
+
+                    postcard::from_bytes(&buf).map_err(|e| StateDecodeError::new(self, e))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::{LocalServerRenderer, ServerRenderer};
+
+    /// A state that always fails to serialize.
+    #[derive(serde::Deserialize)]
+    struct Unencodable;
+
+    impl Serialize for Unencodable {
+        fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("cannot be encoded"))
+        }
+    }
+
+    #[component]
+    fn Comp() -> HtmlResult {
+        let greeting = use_prepared_state!(2u32, |_| -> String { "</script>".to_owned() })?
+            .unwrap_or_default();
+        let _ = use_prepared_state!((), |_| -> u32 { 1 })?;
+
+        Ok(html! { <div>{greeting}</div> })
+    }
+
+    #[cfg(feature = "json")]
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_json_codec() {
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .state_codec(StateCodec::Json)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<div>&lt;/script&gt;</div><script type="application/x-yew-comp-state">[["\u003c/script>",2],[1,null]]</script>"#
+        );
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_default_codec_is_bincode() {
+        let s = LocalServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert!(
+            s.ends_with(
+                r#"<script type="application/x-yew-comp-state">AQk8L3NjcmlwdD4BAg==,AQEB</script>"#
+            ),
+            "{s}"
+        );
+    }
+
+    #[cfg(all(feature = "json", feature = "hydration"))]
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_json_codec_decode() {
+        let states = StateCodec::Json
+            .split(r#"[["\u003c/script>",2],[1,null]]"#)
+            .unwrap();
+        assert_eq!(states.len(), 2);
+
+        let state = StateCodec::Json
+            .decode::<(Option<String>, Option<u32>)>(&states[0])
+            .await
+            .unwrap();
+        assert_eq!(state, (Some("</script>".to_owned()), Some(2)));
+
+        let state = StateCodec::Json
+            .decode::<(Option<u32>, Option<()>)>(&states[0])
+            .await;
+        assert!(state.is_err());
+        assert!(StateCodec::Json.split("AQEA").is_err());
+    }
+
+    #[cfg(feature = "postcard")]
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_postcard_codec() {
+        use base64ct::{Base64, Encoding};
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .state_codec(StateCodec::Postcard)
+            .render()
+            .await;
+
+        let first = postcard::to_allocvec(&(Some("</script>"), Some(2u32))).unwrap();
+        let second = postcard::to_allocvec(&(Some(1u32), Some(()))).unwrap();
+        assert_eq!(
+            s,
+            format!(
+                r#"<div>&lt;/script&gt;</div><script type="application/x-yew-comp-state">{},{}</script>"#,
+                Base64::encode_string(&first),
+                Base64::encode_string(&second),
+            )
+        );
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_unencodable_states_are_not_sent() {
+        #[component]
+        fn Unencoded() -> HtmlResult {
+            let _ = use_prepared_state!((), |_| -> u32 { 1 })?;
+            let _ = use_prepared_state!((), |_| -> Unencodable { Unencodable })?;
+
+            Ok(html! { <div>{"rendered"}</div> })
+        }
+
+        let s = ServerRenderer::<Unencoded>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>rendered</div>");
+    }
+}
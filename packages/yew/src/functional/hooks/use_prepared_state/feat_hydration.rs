@@ -17,7 +17,7 @@ use crate::suspense::{Suspension, SuspensionResult};
     not(target_os = "wasi"),
     not(feature = "not_browser_env")
 ))]
-async fn decode_base64(s: &str) -> Result<Vec<u8>, JsValue> {
+pub(crate) async fn decode_base64(s: &str) -> Result<Vec<u8>, JsValue> {
     use gloo::utils::window;
     use js_sys::Uint8Array;
     use wasm_bindgen::JsCast;
@@ -43,7 +43,7 @@ async fn decode_base64(s: &str) -> Result<Vec<u8>, JsValue> {
     target_os = "wasi",
     feature = "not_browser_env"
 ))]
-async fn decode_base64(_s: &str) -> Result<Vec<u8>, JsValue> {
+pub(crate) async fn decode_base64(_s: &str) -> Result<Vec<u8>, JsValue> {
     unreachable!("this function is not callable under non-wasm targets!");
 }
 
@@ -81,24 +81,21 @@ where
 
             let state = {
                 let data = data.clone();
+                let codec = ctx.state_codec;
+                let scope = ctx.scope.clone();
                 ctx.next_prepared_state(move |_re_render, buf| -> PreparedStateBase<T, D> {
                     if let Some(buf) = buf {
-                        let buf = format!("data:application/octet-binary;base64,{buf}");
+                        let buf = buf.to_owned();
 
                         spawn_local(async move {
-                            let buf = decode_base64(&buf)
-                                .await
-                                .expect("failed to deserialize state");
-
-                            let ((state, deps), _) =
-                                bincode::serde::decode_from_slice::<(Option<T>, Option<D>), _>(
-                                    &buf,
-                                    bincode::config::standard(),
-                                )
-                                .map(|((state, deps), consumed)| {
-                                    ((state.map(Rc::new), deps.map(Rc::new)), consumed)
-                                })
-                                .expect("failed to deserialize state");
+                            let (state, deps) =
+                                match codec.decode::<(Option<T>, Option<D>)>(&buf).await {
+                                    Ok((state, deps)) => (state.map(Rc::new), deps.map(Rc::new)),
+                                    Err(e) => {
+                                        e.report(&scope);
+                                        (None, None)
+                                    }
+                                };
 
                             data.set((Ok((state, deps)), None));
                         });
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "hydration", feature = "ssr"))]
+mod codec;
 #[cfg(feature = "hydration")]
 pub(super) mod feat_hydration;
 #[cfg(all(feature = "hydration", feature = "ssr"))]
@@ -7,6 +9,12 @@ pub(super) mod feat_none;
 #[cfg(feature = "ssr")]
 mod feat_ssr;
 
+#[cfg(any(feature = "hydration", feature = "ssr"))]
+pub use codec::StateCodec;
+#[cfg(feature = "ssr")]
+pub(crate) use codec::StateEncodeError;
+#[cfg(feature = "hydration")]
+pub(crate) use feat_hydration::decode_base64;
 #[cfg(all(feature = "hydration", not(feature = "ssr")))]
 pub use feat_hydration::*;
 #[cfg(all(feature = "ssr", feature = "hydration"))]
@@ -105,6 +113,8 @@ mod feat_any_hydration_ssr {
     use serde::de::DeserializeOwned;
 
     use crate::functional::PreparedState;
+    #[cfg(feature = "ssr")]
+    use crate::functional::{StateCodec, StateEncodeError};
 
     pub(super) struct PreparedStateBase<T, D>
     where
@@ -126,16 +136,8 @@ mod feat_any_hydration_ssr {
         T: Serialize + DeserializeOwned + 'static,
     {
         #[cfg(feature = "ssr")]
-        fn prepare(&self) -> String {
-            use base64ct::{Base64, Encoding};
-
-            let state = bincode::serde::encode_to_vec(
-                (self.state.as_deref(), self.deps.as_deref()),
-                bincode::config::standard(),
-            )
-            .expect("failed to prepare state");
-
-            Base64::encode_string(&state)
+        fn prepare(&self, codec: StateCodec) -> Result<String, StateEncodeError> {
+            codec.encode(&(self.state.as_deref(), self.deps.as_deref()))
         }
     }
 }
@@ -0,0 +1,300 @@
+#[cfg(feature = "ssr")]
+use std::cell::RefCell;
+use std::marker::PhantomData;
+#[cfg(feature = "ssr")]
+use std::rc::Rc;
+
+use crate::functional::{Hook, HookContext};
+
+/// Hook for reading a value passed to the server-side renderer.
+///
+/// Returns the value of type `T` passed to
+/// [`ServerRenderer::with_context`](crate::ServerRenderer::with_context) or
+/// [`LocalServerRenderer::with_context`](crate::LocalServerRenderer::with_context), such as the
+/// URL, headers or cookies of the request being rendered. Returns `None` if no such value has been
+/// passed, and always when the component is not rendered on the server.
+///
+/// Unlike [`use_context`](crate::functional::use_context), the value is available to every
+/// component without a provider and never changes during a render.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// #[derive(Clone)]
+/// struct Locale(String);
+///
+/// #[component]
+/// fn Greeting() -> Html {
+///     let locale = use_server_context::<Locale>().map(|m| m.0);
+///     let greeting = match locale.as_deref() {
+///         Some("fr") => "Bonjour",
+///         _ => "Hello",
+///     };
+///
+///     html! { <p>{greeting}</p> }
+/// }
+/// ```
+pub fn use_server_context<T>() -> impl Hook<Output = Option<T>>
+where
+    T: Clone + 'static,
+{
+    struct HookProvider<T> {
+        _marker: PhantomData<T>,
+    }
+
+    impl<T> Hook for HookProvider<T>
+    where
+        T: Clone + 'static,
+    {
+        type Output = Option<T>;
+
+        #[cfg(feature = "ssr")]
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            use std::any::TypeId;
+
+            use crate::feat_ssr::RenderState;
+
+            let state = RenderState::find(&ctx.scope)?;
+            state
+                .contexts
+                .get(&TypeId::of::<T>())
+                .and_then(|m| m.downcast_ref::<T>())
+                .cloned()
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        fn run(self, _ctx: &mut HookContext) -> Self::Output {
+            None
+        }
+    }
+
+    HookProvider {
+        _marker: PhantomData,
+    }
+}
+
+/// A handle to set the status and the headers of the response of a server-side render.
+///
+/// Returned by [`use_server_response`]. The status and the headers are returned by
+/// [`ServerRenderer::render_response`](crate::ServerRenderer::render_response). They are ignored
+/// by every other render and on the client.
+#[derive(Debug, Clone)]
+pub struct ServerResponseHandle {
+    #[cfg(feature = "ssr")]
+    inner: Option<Rc<RefCell<ResponseParts>>>,
+}
+
+impl ServerResponseHandle {
+    /// Sets the status code of the response.
+    ///
+    /// If multiple components set the status code, the one set last wins.
+    pub fn set_status(&self, status: u16) {
+        #[cfg(feature = "ssr")]
+        if let Some(ref m) = self.inner {
+            m.borrow_mut().status = Some(status);
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        let _ = status;
+    }
+
+    /// Sets the header `name` of the response, replacing the values it has been set to.
+    ///
+    /// Header names are case-insensitive.
+    pub fn insert_header(&self, name: impl Into<String>, value: impl Into<String>) {
+        #[cfg(feature = "ssr")]
+        if let Some(ref m) = self.inner {
+            let name = name.into();
+            let mut parts = m.borrow_mut();
+            parts
+                .headers
+                .retain(|(k, _)| !k.eq_ignore_ascii_case(&name));
+            parts.headers.push((name, value.into()));
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        let _ = (name, value);
+    }
+
+    /// Adds a value to the header `name` of the response, such as a `Set-Cookie` header.
+    pub fn append_header(&self, name: impl Into<String>, value: impl Into<String>) {
+        #[cfg(feature = "ssr")]
+        if let Some(ref m) = self.inner {
+            m.borrow_mut().headers.push((name.into(), value.into()));
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        let _ = (name, value);
+    }
+}
+
+/// Hook for setting the status code and the headers of the response of a server-side render.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// #[component]
+/// fn NotFound() -> Html {
+///     let response = use_server_response();
+///     response.set_status(404);
+///
+///     html! { <h1>{"Not Found"}</h1> }
+/// }
+/// ```
+pub fn use_server_response() -> impl Hook<Output = ServerResponseHandle> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = ServerResponseHandle;
+
+        #[cfg(feature = "ssr")]
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            use crate::feat_ssr::RenderState;
+
+            ServerResponseHandle {
+                inner: RenderState::find(&ctx.scope).and_then(|m| m.response.clone()),
+            }
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        fn run(self, _ctx: &mut HookContext) -> Self::Output {
+            ServerResponseHandle {}
+        }
+    }
+
+    HookProvider
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::any::{Any, TypeId};
+    use std::collections::HashMap;
+
+    /// The values passed to the server-side renderer, by their type.
+    pub(crate) type ServerContexts = HashMap<TypeId, Box<dyn Any>>;
+
+    /// The values passed to a server-side renderer that moves them to another thread.
+    pub(crate) type SendServerContexts = HashMap<TypeId, Box<dyn Any + Send>>;
+
+    /// Converts the values moved to the thread of a render into the values of the render.
+    pub(crate) fn into_local_contexts(contexts: SendServerContexts) -> ServerContexts {
+        contexts
+            .into_iter()
+            .map(|(k, v)| (k, v as Box<dyn Any>))
+            .collect()
+    }
+
+    /// The status and the headers set by the components of a server-side render.
+    #[derive(Debug, Default)]
+    pub(crate) struct ResponseParts {
+        pub status: Option<u16>,
+        pub headers: Vec<(String, String)>,
+    }
+}
+#[cfg(feature = "ssr")]
+pub(crate) use feat_ssr::*;
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::prelude::*;
+    use crate::{LocalServerRenderer, ServerRenderer, ServerResponse};
+
+    #[derive(Clone)]
+    struct RequestPath(String);
+
+    #[component]
+    fn Page() -> Html {
+        let path = use_server_context::<RequestPath>().map(|m| m.0);
+        let response = use_server_response();
+
+        match path.as_deref() {
+            Some("/") => html! { <h1>{"Home"}</h1> },
+            Some("/old") => {
+                response.set_status(308);
+                response.insert_header("location", "/new");
+                response.insert_header("Location", "/");
+                Html::default()
+            }
+            _ => {
+                response.set_status(404);
+                response.append_header("set-cookie", "a=1");
+                response.append_header("set-cookie", "b=2");
+                html! { <h1>{"Not Found"}</h1> }
+            }
+        }
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_server_context() {
+        let s = ServerRenderer::<Page>::new()
+            .hydratable(false)
+            .with_context(RequestPath("/".to_owned()))
+            .render_response()
+            .await;
+
+        assert_eq!(
+            s,
+            ServerResponse {
+                body: "<h1>Home</h1>".to_owned(),
+                status: 200,
+                headers: Vec::new(),
+            }
+        );
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_local_server_context_is_not_send() {
+        use std::rc::Rc;
+
+        #[component]
+        fn Greeting() -> Html {
+            let name = use_server_context::<Rc<str>>().unwrap_or_default();
+
+            html! { <p>{"Hello, "}{name}</p> }
+        }
+
+        let s = LocalServerRenderer::<Greeting>::new()
+            .hydratable(false)
+            .with_context(Rc::<str>::from("Yew"))
+            .render()
+            .await;
+
+        assert_eq!(s, "<p>Hello, Yew</p>");
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_server_response() {
+        let s = LocalServerRenderer::<Page>::new()
+            .hydratable(false)
+            .with_context(RequestPath("/old".to_owned()))
+            .render_response()
+            .await;
+
+        assert_eq!(s.status, 308);
+        assert_eq!(s.headers, vec![("Location".to_owned(), "/".to_owned())]);
+
+        let s = ServerRenderer::<Page>::new()
+            .hydratable(false)
+            .render_response()
+            .await;
+
+        assert_eq!(s.body, "<h1>Not Found</h1>");
+        assert_eq!(s.status, 404);
+        assert_eq!(
+            s.headers,
+            vec![
+                ("set-cookie".to_owned(), "a=1".to_owned()),
+                ("set-cookie".to_owned(), "b=2".to_owned()),
+            ]
+        );
+    }
+}
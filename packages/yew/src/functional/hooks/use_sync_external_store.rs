@@ -0,0 +1,199 @@
+use std::rc::Rc;
+
+use super::{TearDown, use_effect_with, use_force_update, use_mut_ref};
+use crate::callback::Callback;
+use crate::functional::{Hook, HookContext};
+#[cfg(any(feature = "hydration", feature = "ssr"))]
+use crate::html::RenderMode;
+
+struct StoreState<T> {
+    get_snapshot: Option<Rc<dyn Fn() -> T>>,
+    rendered: Option<T>,
+    /// Whether the component has rendered on the client.
+    hydrated: bool,
+}
+
+impl<T> StoreState<T>
+where
+    T: PartialEq,
+{
+    fn changed(&self) -> bool {
+        match (&self.get_snapshot, &self.rendered) {
+            (Some(get_snapshot), Some(rendered)) => get_snapshot() != *rendered,
+            _ => false,
+        }
+    }
+}
+
+/// This hook is used to subscribe to a store that is not managed by Yew.
+///
+/// - `subscribe` is called once the component has rendered for the first time. It receives a
+///   callback that the store must call whenever it changes, and returns a destructor which ends the
+///   subscription when the component is destroyed.
+/// - `get_snapshot` returns the current value of the store. Snapshots are compared with the value
+///   of the last render, and the component only re-renders if the snapshot has changed.
+/// - `get_server_snapshot` returns the value of the store used during server-side rendering. A
+///   hydrating component uses it for its first render as well, so it renders the same content as
+///   the server. Afterwards, it re-renders with the value of `get_snapshot` if they differ.
+///
+/// The snapshot is read again after subscribing, so changes that happen between the render and
+/// the subscription are not missed.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::RefCell;
+///
+/// use yew::prelude::*;
+///
+/// thread_local! {
+///     static COUNT: RefCell<(u32, Vec<Callback<()>>)> = RefCell::default();
+/// }
+///
+/// fn increment() {
+///     let listeners = COUNT.with(|m| {
+///         let mut m = m.borrow_mut();
+///         m.0 += 1;
+///         m.1.clone()
+///     });
+///
+///     for listener in listeners {
+///         listener.emit(());
+///     }
+/// }
+///
+/// #[component]
+/// fn Count() -> Html {
+///     let count = use_sync_external_store(
+///         |on_change| {
+///             COUNT.with(|m| m.borrow_mut().1.push(on_change));
+///         },
+///         || COUNT.with(|m| m.borrow().0),
+///         || 0,
+///     );
+///
+///     html! {
+///         <button onclick={|_| increment()}>{count}</button>
+///     }
+/// }
+/// ```
+pub fn use_sync_external_store<T, S, D, G, GS>(
+    subscribe: S,
+    get_snapshot: G,
+    get_server_snapshot: GS,
+) -> impl Hook<Output = T>
+where
+    T: Clone + PartialEq + 'static,
+    S: FnOnce(Callback<()>) -> D + 'static,
+    D: TearDown,
+    G: Fn() -> T + 'static,
+    GS: FnOnce() -> T,
+{
+    struct HookProvider<S, G, GS> {
+        subscribe: S,
+        get_snapshot: G,
+        get_server_snapshot: GS,
+    }
+
+    impl<T, S, D, G, GS> Hook for HookProvider<S, G, GS>
+    where
+        T: Clone + PartialEq + 'static,
+        S: FnOnce(Callback<()>) -> D + 'static,
+        D: TearDown,
+        G: Fn() -> T + 'static,
+        GS: FnOnce() -> T,
+    {
+        type Output = T;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            // Server-side rendered and hydrating components start with the server snapshot.
+            #[cfg(all(feature = "csr", any(feature = "hydration", feature = "ssr")))]
+            let starts_on_server = ctx.creation_mode != RenderMode::Render;
+            #[cfg(all(feature = "ssr", not(feature = "csr")))]
+            let starts_on_server = ctx.creation_mode == RenderMode::Ssr;
+            #[cfg(not(any(feature = "ssr", feature = "hydration")))]
+            let starts_on_server = false;
+
+            let state = use_mut_ref(|| StoreState {
+                get_snapshot: None,
+                rendered: None,
+                hydrated: false,
+            })
+            .run(ctx);
+            let trigger = use_force_update().run(ctx);
+
+            let value = {
+                let mut state = state.borrow_mut();
+                let value = if starts_on_server && !state.hydrated {
+                    (self.get_server_snapshot)()
+                } else {
+                    (self.get_snapshot)()
+                };
+
+                state.get_snapshot = Some(Rc::new(self.get_snapshot));
+                state.rendered = Some(value.clone());
+
+                value
+            };
+
+            let subscribe = self.subscribe;
+            use_effect_with((), move |_| {
+                state.borrow_mut().hydrated = true;
+
+                let on_change = Callback::from(move |_| {
+                    let changed = state.borrow().changed();
+                    if changed {
+                        trigger.force_update();
+                    }
+                });
+
+                let subscription = subscribe(on_change.clone());
+                // The store may have changed since the component rendered.
+                on_change.emit(());
+
+                move || subscription.tear_down()
+            })
+            .run(ctx);
+
+            value
+        }
+    }
+
+    HookProvider {
+        subscribe,
+        get_snapshot,
+        get_server_snapshot,
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::prelude::*;
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_server_snapshot_is_used() {
+        #[component]
+        fn Comp() -> Html {
+            let value = use_sync_external_store(
+                |_| -> () { panic!("subscribed during server-side rendering") },
+                || "client",
+                || "server",
+            );
+
+            html! { <div>{value}</div> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>server</div>");
+    }
+}
@@ -3,11 +3,10 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use base64ct::{Base64, Encoding};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
-use crate::functional::{Hook, HookContext, PreparedState};
+use crate::functional::{Hook, HookContext, PreparedState, StateCodec, StateEncodeError};
 use crate::suspense::SuspensionResult;
 
 pub(super) struct TransitiveStateBase<T, D, F>
@@ -26,17 +25,11 @@ where
     T: Serialize + DeserializeOwned + 'static,
     F: 'static + FnOnce(Rc<D>) -> T,
 {
-    fn prepare(&self) -> String {
+    fn prepare(&self, codec: StateCodec) -> Result<String, StateEncodeError> {
         let f = self.state_fn.borrow_mut().take().unwrap();
         let state = f(self.deps.clone());
 
-        let state = bincode::serde::encode_to_vec(
-            (Some(&state), Some(&*self.deps)),
-            bincode::config::standard(),
-        )
-        .expect("failed to prepare state");
-
-        Base64::encode_string(&state)
+        codec.encode(&(Some(&state), Some(&*self.deps)))
     }
 }
 
@@ -28,7 +28,7 @@ use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
 use crate::Properties;
-#[cfg(all(feature = "hydration", feature = "ssr"))]
+#[cfg(any(feature = "hydration", feature = "ssr"))]
 use crate::html::RenderMode;
 use crate::html::{AnyScope, BaseComponent, Context, HtmlResult};
 
@@ -73,7 +73,7 @@ type ReRender = Rc<dyn Fn()>;
 #[cfg(any(feature = "hydration", feature = "ssr"))]
 pub(crate) trait PreparedState {
     #[cfg(feature = "ssr")]
-    fn prepare(&self) -> String;
+    fn prepare(&self, codec: StateCodec) -> Result<String, StateEncodeError>;
 }
 
 /// Primitives of an effect hook.
@@ -84,7 +84,7 @@ pub(crate) trait Effect {
 /// A hook context to be passed to hooks.
 pub struct HookContext {
     pub(crate) scope: AnyScope,
-    #[cfg(all(feature = "hydration", feature = "ssr"))]
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
     creation_mode: RenderMode,
     re_render: ReRender,
 
@@ -94,6 +94,8 @@ pub struct HookContext {
     #[cfg(any(feature = "hydration", feature = "ssr"))]
     prepared_states: Vec<Rc<dyn PreparedState>>,
 
+    #[cfg(feature = "hydration")]
+    pub(crate) state_codec: StateCodec,
     #[cfg(feature = "hydration")]
     prepared_states_data: Vec<Rc<str>>,
     #[cfg(feature = "hydration")]
@@ -108,14 +110,26 @@ impl HookContext {
     fn new(
         scope: AnyScope,
         re_render: ReRender,
-        #[cfg(all(feature = "hydration", feature = "ssr"))] creation_mode: RenderMode,
+        #[cfg(any(feature = "hydration", feature = "ssr"))] creation_mode: RenderMode,
         #[cfg(feature = "hydration")] prepared_state: Option<&str>,
     ) -> RefCell<Self> {
+        #[cfg(feature = "hydration")]
+        let state_codec = StateCodec::find(&scope);
+        #[cfg(feature = "hydration")]
+        let prepared_states_data = match prepared_state.map(|m| state_codec.split(m)) {
+            Some(Ok(m)) => m,
+            Some(Err(e)) => {
+                e.report(&scope);
+                Vec::new()
+            }
+            None => Vec::new(),
+        };
+
         RefCell::new(HookContext {
             scope,
             re_render,
 
-            #[cfg(all(feature = "hydration", feature = "ssr"))]
+            #[cfg(any(feature = "hydration", feature = "ssr"))]
             creation_mode,
 
             states: Vec::new(),
@@ -125,12 +139,9 @@ impl HookContext {
             effects: Vec::new(),
 
             #[cfg(feature = "hydration")]
-            prepared_states_data: {
-                match prepared_state {
-                    Some(m) => m.split(',').map(Rc::from).collect(),
-                    None => Vec::new(),
-                }
-            },
+            state_codec,
+            #[cfg(feature = "hydration")]
+            prepared_states_data,
             #[cfg(feature = "hydration")]
             prepared_state_counter: 0,
 
@@ -176,6 +187,12 @@ impl HookContext {
         t
     }
 
+    /// Returns whether the component is rendered by a server renderer.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn is_server_render(&self) -> bool {
+        self.creation_mode == RenderMode::Ssr
+    }
+
     #[cfg(any(feature = "hydration", feature = "ssr"))]
     pub(crate) fn next_prepared_state<T>(
         &mut self,
@@ -276,15 +293,22 @@ impl HookContext {
         }
 
         let prepared_states = self.prepared_states.clone();
-
-        let mut states = Vec::new();
-
-        for state in prepared_states.iter() {
-            let state = state.prepare();
-            states.push(state);
+        let codec = crate::feat_ssr::RenderState::find(&self.scope)
+            .map(|m| m.state_codec)
+            .unwrap_or_default();
+
+        let states = prepared_states
+            .iter()
+            .map(|m| m.prepare(codec))
+            .collect::<Result<Vec<_>, _>>();
+
+        match states {
+            Ok(m) => Some(codec.join(m)),
+            Err(e) => {
+                e.report(&self.scope);
+                None
+            }
         }
-
-        Some(states.join(","))
     }
 }
 
@@ -344,7 +368,7 @@ where
             hook_ctx: HookContext::new(
                 scope,
                 re_render,
-                #[cfg(all(feature = "hydration", feature = "ssr"))]
+                #[cfg(any(feature = "hydration", feature = "ssr"))]
                 ctx.creation_mode(),
                 #[cfg(feature = "hydration")]
                 ctx.prepared_state(),
@@ -0,0 +1,468 @@
+//! This module contains the [`Head`] component, which manages tags in the document head.
+
+use crate::html::{Component, Context, Html, Properties};
+use crate::virtual_dom::VNode;
+
+/// Properties for [Head].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct HeadProps {
+    /// The tags to add to the document head.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Adds tags such as `<title>`, `<meta>` and `<link>` to the document head from any component.
+///
+/// Tags are deduplicated by key. The key of a tag is its [`Key`](crate::virtual_dom::Key) if it
+/// has one. Otherwise, `<title>` and `<base>` tags, `<meta>` tags with a `charset`, `name`,
+/// `property` or `http-equiv` attribute and canonical `<link>` tags are keyed by their kind. If
+/// multiple mounted `Head` components render a tag with the same key, the tag of the most deeply
+/// nested one is used, so pages can override the defaults set by their layout. When that
+/// component is unmounted, the tag of the next one is restored.
+///
+/// # Server-side rendering
+///
+/// The tags are not part of the rendered body. They are collected into the
+/// [`HeadTags`] passed to the server renderer, so they can be written into the head of the
+/// document. Tags rendered by the server are replaced once the application is mounted or
+/// hydrated.
+///
+/// The collected tags are only complete once the whole body has been rendered. When the body is
+/// streamed, the head of the document has usually been sent by then, so the tags can only be
+/// added by the client.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// #[component]
+/// fn Page() -> Html {
+///     html! {
+///         <Head>
+///             <title>{"Yew"}</title>
+///             <meta name="description" content="A framework for making client-side single-page apps" />
+///         </Head>
+///         <h1>{"Yew"}</h1>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Head {
+    keys: Vec<Option<String>>,
+    mounted: bool,
+}
+
+impl Component for Head {
+    type Message = ();
+    type Properties = HeadProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            keys: tags(&ctx.props().children).iter().map(tag_key).collect(),
+            mounted: false,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        let keys: Vec<_> = tags(&ctx.props().children).iter().map(tag_key).collect();
+
+        #[cfg(feature = "csr")]
+        if self.mounted && keys != self.keys {
+            feat_csr::register(ctx, &keys);
+        }
+
+        self.keys = keys;
+
+        true
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        // Portals cannot be hydrated, so the tags are only rendered after the component has been
+        // mounted.
+        if !self.mounted {
+            return Html::default();
+        }
+
+        #[cfg(not(feature = "csr"))]
+        let html = Html::default();
+
+        #[cfg(feature = "csr")]
+        let html = feat_csr::render_owned(_ctx, &self.keys);
+
+        html
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        #[cfg(feature = "csr")]
+        if first_render {
+            feat_csr::remove_server_tags();
+            feat_csr::register(_ctx, &self.keys);
+
+            self.mounted = true;
+            _ctx.link().send_message(());
+        }
+
+        #[cfg(not(feature = "csr"))]
+        let _ = first_render;
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        #[cfg(feature = "csr")]
+        feat_csr::unregister(_ctx);
+    }
+}
+
+/// Flattens the tags of a [`Head`].
+fn tags(children: &Html) -> Vec<VNode> {
+    match children {
+        VNode::VList(m) => m.iter().flat_map(tags).collect(),
+        m => vec![m.clone()],
+    }
+}
+
+/// Returns the key used to deduplicate a tag.
+fn tag_key(node: &VNode) -> Option<String> {
+    if let Some(m) = node.key() {
+        return Some(format!("key:{m}"));
+    }
+
+    let VNode::VTag(tag) = node else {
+        return None;
+    };
+    let attr = |name: &str| {
+        tag.attributes
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    };
+
+    match tag.tag().to_ascii_lowercase().as_str() {
+        m @ ("title" | "base") => Some(m.to_owned()),
+        "meta" if attr("charset").is_some() => Some("meta:charset".to_owned()),
+        "meta" => ["name", "property", "http-equiv"]
+            .into_iter()
+            .find_map(|name| Some(format!("meta:{name}={}", attr(name)?))),
+        "link" if attr("rel").is_some_and(|m| m.eq_ignore_ascii_case("canonical")) => {
+            Some("link:canonical".to_owned())
+        }
+        _ => None,
+    }
+}
+
+/// The attribute of the tags rendered by the server.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+const SERVER_TAG_ATTR: &str = "data-yew-head";
+
+/// The depth of a component in the tree, which decides the [`Head`] a key belongs to.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+fn depth(scope: &crate::html::AnyScope) -> usize {
+    std::iter::successors(scope.get_parent(), |m| m.get_parent()).count()
+}
+
+#[cfg(feature = "csr")]
+mod feat_csr {
+    use std::cell::RefCell;
+
+    use gloo::utils::document;
+    use wasm_bindgen::JsCast;
+    use web_sys::Element;
+
+    use super::*;
+    use crate::callback::Callback;
+    use crate::html::{AnyScope, create_portal};
+    use crate::virtual_dom::VList;
+
+    struct Registration {
+        id: usize,
+        depth: usize,
+        keys: Vec<String>,
+        refresh: Callback<()>,
+    }
+
+    #[derive(Default)]
+    struct HeadManager {
+        /// Registrations in the order they have been made.
+        registrations: Vec<Registration>,
+        server_tags_removed: bool,
+    }
+
+    thread_local! {
+        static MANAGER: RefCell<HeadManager> = RefCell::default();
+    }
+
+    /// Notifies the mounted heads other than `id` that render any of `keys` that the owners of
+    /// these keys may have changed.
+    fn refresh(id: usize, keys: &[String]) {
+        if keys.is_empty() {
+            return;
+        }
+
+        let refreshes: Vec<_> = MANAGER.with(|m| {
+            m.borrow()
+                .registrations
+                .iter()
+                .filter(|m| m.id != id && m.keys.iter().any(|key| keys.contains(key)))
+                .map(|m| m.refresh.clone())
+                .collect()
+        });
+
+        for m in refreshes {
+            m.emit(());
+        }
+    }
+
+    /// Registers the keys rendered by the head of `ctx`.
+    pub(super) fn register(ctx: &Context<Head>, keys: &[Option<String>]) {
+        let scope = ctx.link();
+        let registration = Registration {
+            id: scope.id,
+            depth: depth(&AnyScope::from(scope.clone())),
+            keys: keys.iter().flatten().cloned().collect(),
+            refresh: scope.callback(|_| ()),
+        };
+
+        let id = registration.id;
+        let old_keys = MANAGER.with(|m| {
+            let registrations = &mut m.borrow_mut().registrations;
+            match registrations.iter_mut().find(|m| m.id == id) {
+                Some(m) => std::mem::replace(m, registration).keys,
+                None => {
+                    registrations.push(registration);
+                    Vec::new()
+                }
+            }
+        });
+
+        // Only the keys that have been added or removed can change their owner.
+        let new_keys: Vec<_> = keys.iter().flatten().cloned().collect();
+        let changed: Vec<_> = old_keys
+            .iter()
+            .filter(|m| !new_keys.contains(m))
+            .chain(new_keys.iter().filter(|m| !old_keys.contains(m)))
+            .cloned()
+            .collect();
+        refresh(id, &changed);
+    }
+
+    pub(super) fn unregister(ctx: &Context<Head>) {
+        let id = ctx.link().id;
+        let removed = MANAGER.with(|m| {
+            let registrations = &mut m.borrow_mut().registrations;
+            let index = registrations.iter().position(|m| m.id == id)?;
+
+            Some(registrations.remove(index))
+        });
+
+        if let Some(m) = removed {
+            refresh(id, &m.keys);
+        }
+    }
+
+    /// Removes the tags rendered by the server, as they are rendered by the mounted heads.
+    pub(super) fn remove_server_tags() {
+        let removed =
+            MANAGER.with(|m| std::mem::replace(&mut m.borrow_mut().server_tags_removed, true));
+        if removed {
+            return;
+        }
+
+        let Some(head) = document().head() else {
+            return;
+        };
+        let Ok(tags) = head.query_selector_all(&format!("[{SERVER_TAG_ATTR}]")) else {
+            return;
+        };
+
+        for i in 0..tags.length() {
+            if let Some(m) = tags.get(i).and_then(|m| m.dyn_into::<Element>().ok()) {
+                m.remove();
+            }
+        }
+    }
+
+    /// Renders the tags of the head of `ctx` whose keys are not rendered by a nested head.
+    pub(super) fn render_owned(ctx: &Context<Head>, keys: &[Option<String>]) -> Html {
+        let id = ctx.link().id;
+        let owned = MANAGER.with(|m| {
+            let registrations = &m.borrow().registrations;
+            let owns = |key: &String| {
+                registrations
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, m)| m.keys.contains(key))
+                    .max_by_key(|(index, m)| (m.depth, *index))
+                    .is_some_and(|(_, m)| m.id == id)
+            };
+
+            keys.iter()
+                .map(|m| m.as_ref().is_none_or(owns))
+                .collect::<Vec<_>>()
+        });
+
+        let tags = tags(&ctx.props().children)
+            .into_iter()
+            .zip(owned)
+            .filter_map(|(tag, owned)| owned.then_some(tag));
+
+        match document().head() {
+            Some(head) => create_portal(tags.collect::<VList>().into(), head.into()),
+            None => Html::default(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::sync::{Arc, Mutex};
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::feat_ssr::RenderState;
+    use crate::html::{AnyScope, Scope};
+    use crate::platform::fmt;
+
+    #[derive(Debug)]
+    struct CollectedTag {
+        key: Option<String>,
+        depth: usize,
+        html: String,
+    }
+
+    /// The tags collected from the [`Head`] components of a server-side render.
+    ///
+    /// Pass it to [`ServerRenderer::head`](crate::ServerRenderer::head) and write
+    /// [`HeadTags::render`] into the head of the document once the body has been rendered.
+    ///
+    /// The tags are only complete once the render has finished, so they cannot be written into
+    /// the head of a document whose body is [streamed](crate::ServerRenderer::render_stream)
+    /// after the head has been sent. Streamed documents get their tags from the client once the
+    /// application has been hydrated.
+    #[derive(Debug, Clone, Default)]
+    pub struct HeadTags {
+        inner: Arc<Mutex<Vec<CollectedTag>>>,
+    }
+
+    impl HeadTags {
+        /// Creates an empty collection of tags.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Renders the collected tags.
+        pub fn render(&self) -> String {
+            let tags = self.inner.lock().unwrap();
+            tags.iter().map(|m| m.html.as_str()).collect()
+        }
+
+        fn push(&self, tag: CollectedTag) {
+            let mut tags = self.inner.lock().unwrap();
+            let existing = tag
+                .key
+                .as_ref()
+                .and_then(|key| tags.iter().position(|m| m.key.as_ref() == Some(key)));
+
+            match existing {
+                // Heads are rendered in document order, so the later one wins among heads of the
+                // same depth.
+                Some(i) if tags[i].depth <= tag.depth => {
+                    tags[i] = tag;
+                }
+                Some(_) => {}
+                None => tags.push(tag),
+            }
+        }
+    }
+
+    impl Head {
+        /// Collects the tags of a head into the [`HeadTags`] of the render.
+        pub(crate) async fn render_into_stream(scope: &Scope<Self>, props: &HeadProps) {
+            let scope = AnyScope::from(scope.clone());
+            let Some(head) = RenderState::find(&scope).and_then(|m| m.head.clone()) else {
+                return;
+            };
+            let depth = depth(&scope);
+
+            for node in tags(&props.children) {
+                let key = tag_key(&node);
+                let node = match node {
+                    VNode::VTag(m) => {
+                        let mut tag = (*m).clone();
+                        tag.add_attribute(SERVER_TAG_ATTR, "");
+                        VNode::from(tag)
+                    }
+                    m => m,
+                };
+
+                let (mut w, r) = fmt::buffer();
+                node.render_into_stream(&mut w, &scope, false, Default::default())
+                    .await;
+                drop(w);
+
+                let html = r.collect().await;
+                head.push(CollectedTag { key, depth, html });
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use feat_ssr::*;
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::LocalServerRenderer as ServerRenderer;
+    use crate::prelude::*;
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_head_tags_are_collected() {
+        #[component]
+        fn Page() -> Html {
+            html! {
+                <Head>
+                    <title>{"Page"}</title>
+                    <link rel="canonical" href="https://yew.rs/page" />
+                </Head>
+                <h1>{"Page"}</h1>
+            }
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <Head>
+                    <title>{"Yew"}</title>
+                    <meta name="description" content="Yew" />
+                </Head>
+                <Page />
+            }
+        }
+
+        let head = HeadTags::new();
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .head(head.clone())
+            .render()
+            .await;
+
+        assert_eq!(s, "<h1>Page</h1>");
+        assert_eq!(
+            head.render(),
+            concat!(
+                r#"<title data-yew-head="">Page</title>"#,
+                r#"<meta name="description" content="Yew" data-yew-head="">"#,
+                r#"<link rel="canonical" href="https://yew.rs/page" data-yew-head="">"#,
+            )
+        );
+    }
+}
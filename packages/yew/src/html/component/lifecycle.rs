@@ -1,6 +1,7 @@
 //! Component lifecycle module
 
 use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
 
 #[cfg(feature = "csr")]
@@ -8,15 +9,15 @@ use web_sys::Element;
 
 use super::BaseComponent;
 use super::scope::{AnyScope, Scope};
-#[cfg(feature = "hydration")]
-use crate::dom_bundle::Fragment;
 #[cfg(feature = "csr")]
 use crate::dom_bundle::{BSubtree, Bundle, DomSlot, DynamicDomSlot};
 #[cfg(feature = "hydration")]
+use crate::dom_bundle::{Fragment, mismatch};
+#[cfg(any(feature = "hydration", feature = "ssr"))]
 use crate::html::RenderMode;
-use crate::html::{Html, RenderError};
+use crate::html::{Html, RenderError, catch_error, catch_panic};
 use crate::scheduler::{self, Runnable, Shared};
-use crate::suspense::{BaseSuspense, Suspension};
+use crate::suspense::{BaseSuspense, Suspension, Transition};
 use crate::{Callback, Context, HtmlResult};
 
 pub(crate) enum ComponentRenderState {
@@ -233,6 +234,10 @@ pub(crate) struct ComponentState {
     /// props. This is prop updates are ignored during SSR, too.
     #[cfg(feature = "hydration")]
     pending_props: Option<Rc<dyn Any>>,
+    /// The bundle and the remaining server markup of a component that failed to hydrate.
+    /// They are discarded once the component has been rendered on the client.
+    #[cfg(feature = "hydration")]
+    stale: Option<(Bundle, Fragment)>,
 
     suspension: Option<Suspension>,
 
@@ -253,10 +258,12 @@ impl ComponentState {
         #[cfg(feature = "hydration")] prepared_state: Option<String>,
     ) -> Self {
         let comp_id = scope.id;
-        #[cfg(feature = "hydration")]
+        #[cfg(any(feature = "hydration", feature = "ssr"))]
         let creation_mode = {
             match initial_render_state {
+                #[cfg(feature = "csr")]
                 ComponentRenderState::Render { .. } => RenderMode::Render,
+                #[cfg(feature = "hydration")]
                 ComponentRenderState::Hydration { .. } => RenderMode::Hydration,
                 #[cfg(feature = "ssr")]
                 ComponentRenderState::Ssr { .. } => RenderMode::Ssr,
@@ -266,7 +273,7 @@ impl ComponentState {
         let context = Context {
             scope,
             props,
-            #[cfg(feature = "hydration")]
+            #[cfg(any(feature = "hydration", feature = "ssr"))]
             creation_mode,
             #[cfg(feature = "hydration")]
             prepared_state,
@@ -286,6 +293,8 @@ impl ComponentState {
             has_rendered: false,
             #[cfg(feature = "hydration")]
             pending_props: None,
+            #[cfg(feature = "hydration")]
+            stale: None,
 
             comp_id,
         }
@@ -301,6 +310,31 @@ impl ComponentState {
             .map(|m| &m.component)
     }
 
+    /// Runs a lifecycle method of the component.
+    ///
+    /// Returns [`None`] if the method panicked, in which case the panic has been handed to the
+    /// nearest error boundary.
+    fn guard<R>(&mut self, f: impl FnOnce(&mut dyn Stateful) -> R) -> Option<R> {
+        match panic::catch_unwind(AssertUnwindSafe(|| f(self.inner.as_mut()))) {
+            Ok(m) => Some(m),
+            Err(payload) => {
+                self.abort_ssr();
+                catch_panic(&self.inner.any_scope(), payload);
+                None
+            }
+        }
+    }
+
+    /// Ends server-side rendering of a component that failed to render.
+    fn abort_ssr(&mut self) {
+        // Dropping the sender ends server-side rendering of this component.
+        #[cfg(feature = "ssr")]
+        #[allow(irrefutable_let_patterns)]
+        if let ComponentRenderState::Ssr { sender } = &mut self.render_state {
+            sender.take();
+        }
+    }
+
     fn resume_existing_suspension(&mut self) {
         if let Some(m) = self.suspension.take() {
             let comp_scope = self.inner.any_scope();
@@ -321,15 +355,32 @@ pub(crate) struct CreateRunner<COMP: BaseComponent> {
 
 impl<COMP: BaseComponent> Runnable for CreateRunner<COMP> {
     fn run(self: Box<Self>) {
-        let mut current_state = self.scope.state.borrow_mut();
+        let Self {
+            initial_render_state,
+            props,
+            scope,
+            #[cfg(feature = "hydration")]
+            prepared_state,
+        } = *self;
+
+        let mut current_state = scope.state.borrow_mut();
         if current_state.is_none() {
-            *current_state = Some(ComponentState::new(
-                self.initial_render_state,
-                self.scope.clone(),
-                self.props,
-                #[cfg(feature = "hydration")]
-                self.prepared_state,
-            ));
+            let state = panic::catch_unwind(AssertUnwindSafe(|| {
+                ComponentState::new(
+                    initial_render_state,
+                    scope.clone(),
+                    props,
+                    #[cfg(feature = "hydration")]
+                    prepared_state,
+                )
+            }));
+
+            match state {
+                Ok(m) => *current_state = Some(m),
+                // The render state is dropped during unwinding, which also ends server-side
+                // rendering of this component.
+                Err(payload) => catch_panic(&scope.clone().into(), payload),
+            }
         }
     }
 }
@@ -345,7 +396,7 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn update(&mut self) -> bool {
-        let schedule_render = self.inner.flush_messages();
+        let schedule_render = self.guard(|m| m.flush_messages()).unwrap_or(false);
         tracing::trace!(schedule_render);
         schedule_render
     }
@@ -381,6 +432,8 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn destroy(mut self, parent_to_detach: bool) {
+        #[cfg(feature = "hydration")]
+        DeferredHydration::resume(self.comp_id);
         self.inner.destroy();
         self.resume_existing_suspension();
 
@@ -392,6 +445,11 @@ impl ComponentState {
                 ref root,
                 ..
             } => {
+                #[cfg(feature = "hydration")]
+                if let Some((stale_bundle, stale_fragment)) = self.stale.take() {
+                    stale_bundle.detach(root, parent, parent_to_detach);
+                    stale_fragment.detach(root, parent, parent_to_detach);
+                }
                 bundle.detach(root, parent, parent_to_detach);
             }
             // We need to detach the hydrate fragment if the component is not hydrated.
@@ -432,15 +490,44 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn render(&mut self, shared_state: &Shared<Option<ComponentState>>) {
-        let view = self.inner.view();
+        // Components that delay their hydration keep their server markup as is.
+        #[cfg(feature = "hydration")]
+        if let ComponentRenderState::Hydration { ref fragment, .. } = self.render_state {
+            if DeferredHydration::is_deferred(self.comp_id, fragment) {
+                return;
+            }
+        }
+
+        // The transition this render has been scheduled in, if any.
+        #[cfg(feature = "csr")]
+        let transition = Transition::take(self.comp_id);
+        #[cfg(not(feature = "csr"))]
+        let transition = None;
+
+        let Some(view) = self.guard(|m| m.view()) else {
+            return;
+        };
         tracing::trace!(?view, "render result");
         match view {
+            // Components updated while committing are rendered in the same transition.
+            #[cfg(feature = "csr")]
+            Ok(vnode) => Transition::enter(transition, || self.commit_render(shared_state, vnode)),
+            #[cfg(not(feature = "csr"))]
             Ok(vnode) => self.commit_render(shared_state, vnode),
-            Err(RenderError::Suspended(susp)) => self.suspend(shared_state, susp),
+            Err(RenderError::Suspended(susp)) => self.suspend(shared_state, susp, transition),
+            Err(RenderError::Failed(e)) => {
+                self.abort_ssr();
+                catch_error(&self.inner.any_scope(), e);
+            }
         };
     }
 
-    fn suspend(&mut self, shared_state: &Shared<Option<ComponentState>>, suspension: Suspension) {
+    fn suspend(
+        &mut self,
+        shared_state: &Shared<Option<ComponentState>>,
+        suspension: Suspension,
+        transition: Option<Transition>,
+    ) {
         // Currently suspended, we re-use previous root node and send
         // suspension to parent element.
 
@@ -480,7 +567,7 @@ impl ComponentState {
             }
             self.suspension = Some(suspension.clone());
 
-            BaseSuspense::suspend(&suspense_scope, suspension);
+            BaseSuspense::suspend(&suspense_scope, suspension, transition);
         }
     }
 
@@ -501,6 +588,12 @@ impl ComponentState {
             } => {
                 let scope = self.inner.any_scope();
 
+                #[cfg(feature = "hydration")]
+                if let Some((stale_bundle, stale_fragment)) = self.stale.take() {
+                    stale_bundle.detach(root, parent, false);
+                    stale_fragment.detach(root, parent, false);
+                }
+
                 let new_node_ref =
                     bundle.reconcile(root, &scope, parent, sibling_slot.to_position(), new_vdom);
                 own_slot.reassign(new_node_ref);
@@ -537,7 +630,7 @@ impl ComponentState {
                 );
 
                 let scope = self.inner.any_scope();
-                let bundle = Bundle::hydrate(
+                let mut bundle = Bundle::hydrate(
                     root,
                     &scope,
                     parent,
@@ -548,7 +641,18 @@ impl ComponentState {
 
                 // We trim all text nodes before checking as it's likely these are whitespaces.
                 fragment.trim_start_text_nodes();
-                assert!(fragment.is_empty(), "expected end of component, found node");
+                if !fragment.is_empty() {
+                    mismatch::record("end of component", fragment.front());
+                }
+
+                // The component is rendered on the client by the render scheduled above, which
+                // also discards what has been hydrated so far.
+                if let Some(diff) = mismatch::take() {
+                    diff.report(&scope);
+
+                    let stale_bundle = std::mem::replace(&mut bundle, Bundle::new());
+                    self.stale = Some((stale_bundle, std::mem::take(fragment)));
+                }
 
                 self.render_state = ComponentRenderState::Render {
                     root: root.clone(),
@@ -582,6 +686,64 @@ impl Runnable for RenderRunner {
     }
 }
 
+#[cfg(feature = "hydration")]
+mod feat_hydration {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use web_sys::Node;
+
+    use super::*;
+
+    type OnDeferred = Box<dyn FnOnce(&[Node])>;
+
+    thread_local! {
+        /// Components that delay their hydration, keyed by component id.
+        ///
+        /// The callback is taken once the first render of the component has been delayed.
+        static DEFERRED: RefCell<HashMap<usize, Option<OnDeferred>>> =
+            RefCell::new(HashMap::new());
+    }
+
+    /// The registry of components that delay their hydration.
+    pub(crate) struct DeferredHydration;
+
+    impl DeferredHydration {
+        /// Delays the hydration of the component until [`DeferredHydration::resume`] is called.
+        ///
+        /// `on_deferred` is called with the server markup of the component once its first render
+        /// has been delayed.
+        pub fn defer(comp_id: usize, on_deferred: impl FnOnce(&[Node]) + 'static) {
+            DEFERRED.with(|m| m.borrow_mut().insert(comp_id, Some(Box::new(on_deferred))));
+        }
+
+        /// Stops delaying the hydration of the component.
+        ///
+        /// Returns whether its hydration was delayed.
+        pub fn resume(comp_id: usize) -> bool {
+            DEFERRED.with(|m| m.borrow_mut().remove(&comp_id)).is_some()
+        }
+
+        /// Returns whether the hydration of the component is delayed.
+        pub(super) fn is_deferred(comp_id: usize, fragment: &Fragment) -> bool {
+            let Some(on_deferred) =
+                DEFERRED.with(|m| m.borrow_mut().get_mut(&comp_id).map(Option::take))
+            else {
+                return false;
+            };
+
+            if let Some(on_deferred) = on_deferred {
+                let nodes = fragment.iter().cloned().collect::<Vec<_>>();
+                on_deferred(&nodes);
+            }
+
+            true
+        }
+    }
+}
+#[cfg(feature = "hydration")]
+pub(crate) use feat_hydration::*;
+
 #[cfg(feature = "csr")]
 mod feat_csr {
     use super::*;
@@ -627,7 +789,9 @@ mod feat_csr {
             }
 
             let should_render = |props: Option<Rc<dyn Any>>, state: &mut ComponentState| -> bool {
-                props.map(|m| state.inner.props_changed(m)).unwrap_or(false)
+                props
+                    .and_then(|m| state.guard(|inner| inner.props_changed(m)))
+                    .unwrap_or(false)
             };
 
             #[cfg(feature = "hydration")]
@@ -637,7 +801,7 @@ mod feat_csr {
                         Some(props) => match state.has_rendered {
                             true => {
                                 state.pending_props = None;
-                                state.inner.props_changed(props)
+                                state.guard(|m| m.props_changed(props)).unwrap_or(false)
                             }
                             false => {
                                 state.pending_props = Some(props);
@@ -709,7 +873,7 @@ mod feat_csr {
         )]
         fn rendered(&mut self, first_render: bool) -> bool {
             if self.suspension.is_none() {
-                self.inner.rendered(first_render);
+                self.guard(|m| m.rendered(first_render));
             }
 
             #[cfg(feature = "hydration")]
@@ -18,10 +18,12 @@ pub use scope::{AnyScope, Scope, SendAsMessage};
 
 use super::{Html, HtmlResult, IntoHtmlResult};
 
-#[cfg(feature = "hydration")]
+#[cfg(any(feature = "hydration", feature = "ssr"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum RenderMode {
+    #[cfg(feature = "hydration")]
     Hydration,
+    #[cfg(feature = "csr")]
     Render,
     #[cfg(feature = "ssr")]
     Ssr,
@@ -33,7 +35,7 @@ pub(crate) enum RenderMode {
 pub struct Context<COMP: BaseComponent> {
     scope: Scope<COMP>,
     props: Rc<COMP::Properties>,
-    #[cfg(feature = "hydration")]
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
     creation_mode: RenderMode,
 
     #[cfg(feature = "hydration")]
@@ -53,7 +55,13 @@ impl<COMP: BaseComponent> Context<COMP> {
         &self.props
     }
 
-    #[cfg(feature = "hydration")]
+    /// The component's props, shared with the virtual dom that rendered the component.
+    #[inline]
+    pub(crate) fn shared_props(&self) -> &Rc<COMP::Properties> {
+        &self.props
+    }
+
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
     pub(crate) fn creation_mode(&self) -> RenderMode {
         self.creation_mode
     }
@@ -255,6 +263,8 @@ mod tests {
             props: Rc::new(()),
             #[cfg(feature = "hydration")]
             creation_mode: crate::html::RenderMode::Hydration,
+            #[cfg(all(feature = "ssr", not(feature = "hydration")))]
+            creation_mode: crate::html::RenderMode::Ssr,
             #[cfg(feature = "hydration")]
             prepared_state: None,
         };
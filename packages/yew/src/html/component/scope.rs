@@ -23,6 +23,15 @@ pub struct AnyScope {
     type_id: TypeId,
     parent: Option<Rc<AnyScope>>,
     typed_scope: Rc<dyn Any>,
+
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) position: Rc<TreePosition>,
+
+    #[cfg(any(feature = "ssr", feature = "hydration"))]
+    pub(crate) id: usize,
+
+    #[cfg(any(feature = "ssr", feature = "hydration"))]
+    pub(crate) type_name: &'static str,
 }
 
 impl fmt::Debug for AnyScope {
@@ -36,6 +45,12 @@ impl<COMP: BaseComponent> From<Scope<COMP>> for AnyScope {
         AnyScope {
             type_id: TypeId::of::<COMP>(),
             parent: scope.parent.clone(),
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            position: scope.position.clone(),
+            #[cfg(any(feature = "ssr", feature = "hydration"))]
+            id: scope.id,
+            #[cfg(any(feature = "ssr", feature = "hydration"))]
+            type_name: std::any::type_name::<COMP>(),
             typed_scope: Rc::new(scope),
         }
     }
@@ -100,6 +115,9 @@ pub struct Scope<COMP: BaseComponent> {
     #[cfg(any(feature = "csr", feature = "ssr"))]
     pub(crate) state: Shared<Option<ComponentState>>,
 
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) position: Rc<TreePosition>,
+
     pub(crate) id: usize,
 }
 
@@ -121,6 +139,9 @@ impl<COMP: BaseComponent> Clone for Scope<COMP> {
             #[cfg(any(feature = "csr", feature = "ssr"))]
             state: self.state.clone(),
 
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            position: self.position.clone(),
+
             id: self.id,
         }
     }
@@ -293,12 +314,15 @@ mod feat_ssr {
 
     use super::*;
     use crate::feat_ssr::VTagKind;
+    use crate::head::{Head, HeadProps};
     use crate::html::component::lifecycle::{
         ComponentRenderState, CreateRunner, DestroyRunner, RenderRunner,
     };
+    use crate::html::{BaseErrorBoundary, BaseErrorBoundaryProps};
     use crate::platform::fmt::BufWriter;
     use crate::platform::pinned::oneshot;
     use crate::scheduler;
+    use crate::suspense::DeferredRender;
     use crate::virtual_dom::Collectable;
 
     impl<COMP: BaseComponent> Scope<COMP> {
@@ -315,6 +339,12 @@ mod feat_ssr {
             // similar to taking the value from a mutex lock.
             let (tx, rx) = oneshot::channel();
             let state = ComponentRenderState::Ssr { sender: Some(tx) };
+            let boundary_props = (Rc::clone(&props) as Rc<dyn Any>)
+                .downcast::<BaseErrorBoundaryProps>()
+                .ok();
+            let head_props = (Rc::clone(&props) as Rc<dyn Any>)
+                .downcast::<HeadProps>()
+                .ok();
 
             scheduler::push_component_create(
                 self.id,
@@ -331,17 +361,52 @@ mod feat_ssr {
             );
             scheduler::start();
 
+            // Destroys the component once it has been rendered, or when the render is dropped
+            // before that, e.g. as it has timed out.
+            let _destroy = DestroyOnDrop(self);
+
             let collectable = Collectable::for_component::<COMP>();
 
             if hydratable {
                 collectable.write_open_tag(w);
             }
 
-            let html = rx.await.unwrap();
-
             let self_any_scope = AnyScope::from(self.clone());
-            html.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
-                .await;
+
+            let html = match rx.await {
+                Ok(m) => m,
+                // The component panicked and the failure has been handed to an error boundary,
+                // which discards the output of this subtree.
+                Err(_) => {
+                    if hydratable {
+                        collectable.write_close_tag(w);
+                    }
+
+                    return;
+                }
+            };
+
+            match (boundary_props, head_props) {
+                (Some(props), _) => {
+                    BaseErrorBoundary::render_into_stream(
+                        &self_any_scope.downcast::<BaseErrorBoundary>(),
+                        &props,
+                        html,
+                        w,
+                        hydratable,
+                        parent_vtag_kind,
+                    )
+                    .await;
+                }
+                // The tags of a head are not part of the body.
+                (_, Some(props)) => {
+                    Head::render_into_stream(&self_any_scope.downcast::<Head>(), &props).await;
+                }
+                (None, None) => {
+                    html.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
+                        .await;
+                }
+            }
 
             if let Some(prepared_state) = self.get_component().unwrap().prepare_state() {
                 let _ = w.write_str(r#"<script type="application/x-yew-comp-state">"#);
@@ -352,12 +417,28 @@ mod feat_ssr {
             if hydratable {
                 collectable.write_close_tag(w);
             }
+        }
 
-            scheduler::push_component_destroy(Box::new(DestroyRunner {
+        fn destroy_rendered(&self, any_scope: &AnyScope) {
+            let runner = Box::new(DestroyRunner {
                 state: self.state.clone(),
                 parent_to_detach: false,
-            }));
-            scheduler::start();
+            });
+
+            // Components streamed out of order are destroyed once the entire document has been
+            // rendered.
+            if let Some(runner) = DeferredRender::destroy_later(any_scope, runner) {
+                scheduler::push_component_destroy(runner);
+                scheduler::start();
+            }
+        }
+    }
+
+    struct DestroyOnDrop<'a, COMP: BaseComponent>(&'a Scope<COMP>);
+
+    impl<COMP: BaseComponent> Drop for DestroyOnDrop<'_, COMP> {
+        fn drop(&mut self) {
+            self.0.destroy_rendered(&AnyScope::from(self.0.clone()));
         }
     }
 }
@@ -384,12 +465,14 @@ mod feat_no_csr_ssr {
 
 #[cfg(any(feature = "ssr", feature = "csr"))]
 mod feat_csr_ssr {
-    use std::cell::{Ref, RefCell};
+    use std::cell::{Cell, Ref, RefCell};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     use super::*;
     use crate::html::component::lifecycle::UpdateRunner;
     use crate::scheduler::{self, Shared};
+    #[cfg(feature = "csr")]
+    use crate::suspense::Transition;
 
     #[derive(Debug)]
     pub(crate) struct MsgQueue<Msg>(Shared<Vec<Msg>>);
@@ -431,9 +514,49 @@ mod feat_csr_ssr {
 
     static COMP_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
+    /// The position of a component in the tree.
+    ///
+    /// Components are numbered in the order they are created by their parent. As a server-side
+    /// render and hydration create the components of a parent in the same order, a position
+    /// refers to the same component in both.
+    #[derive(Debug, Default)]
+    pub(crate) struct TreePosition {
+        parent: Option<Rc<TreePosition>>,
+        index: usize,
+        next_child: Cell<usize>,
+    }
+
+    impl TreePosition {
+        fn child(self: &Rc<Self>) -> Rc<Self> {
+            let index = self.next_child.get();
+            self.next_child.set(index + 1);
+
+            Rc::new(Self {
+                parent: Some(self.clone()),
+                index,
+                next_child: Cell::new(0),
+            })
+        }
+
+        /// Returns the indices of the component and its ancestors, starting at the root.
+        pub(crate) fn path(&self) -> Vec<usize> {
+            let mut path: Vec<_> = iter::successors(Some(self), |m| m.parent.as_deref())
+                .filter(|m| m.parent.is_some())
+                .map(|m| m.index)
+                .collect();
+            path.reverse();
+
+            path
+        }
+    }
+
     impl<COMP: BaseComponent> Scope<COMP> {
         /// Crate a scope with an optional parent scope
         pub(crate) fn new(parent: Option<AnyScope>) -> Self {
+            let position = match parent {
+                Some(ref m) => m.position.child(),
+                None => Rc::default(),
+            };
             let parent = parent.map(Rc::new);
 
             let state = Rc::new(RefCell::new(None));
@@ -447,6 +570,7 @@ mod feat_csr_ssr {
 
                 state,
                 parent,
+                position,
 
                 id: COMP_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
             }
@@ -476,6 +600,9 @@ mod feat_csr_ssr {
         where
             T: Into<COMP::Message>,
         {
+            #[cfg(feature = "csr")]
+            Transition::mark(self.id);
+
             // We are the first message in queue, so we queue the update.
             if self.pending_messages.push(msg.into()) == 1 {
                 self.schedule_update();
@@ -484,6 +611,9 @@ mod feat_csr_ssr {
 
         #[inline]
         pub(super) fn arch_send_message_batch(&self, mut messages: Vec<COMP::Message>) {
+            #[cfg(feature = "csr")]
+            Transition::mark(self.id);
+
             let msg_len = messages.len();
 
             // The queue was empty, so we queue the update
@@ -501,7 +631,7 @@ pub(crate) use feat_csr_ssr::*;
 mod feat_csr {
     use std::cell::Ref;
 
-    use web_sys::Element;
+    use web_sys::{Element, Node};
 
     use super::*;
     use crate::dom_bundle::{BSubtree, Bundle, DomSlot, DynamicDomSlot};
@@ -509,6 +639,7 @@ mod feat_csr {
         ComponentRenderState, CreateRunner, DestroyRunner, PropsUpdateRunner, RenderRunner,
     };
     use crate::scheduler;
+    use crate::suspense::Transition;
 
     impl AnyScope {
         #[cfg(any(test, feature = "test"))]
@@ -517,6 +648,11 @@ mod feat_csr {
                 type_id: TypeId::of::<()>(),
                 parent: None,
                 typed_scope: Rc::new(()),
+                position: Rc::default(),
+                #[cfg(any(feature = "ssr", feature = "hydration"))]
+                id: usize::MAX,
+                #[cfg(any(feature = "ssr", feature = "hydration"))]
+                type_name: "()",
             }
         }
     }
@@ -551,6 +687,7 @@ mod feat_csr {
             let sibling_slot = DynamicDomSlot::new(slot);
             let own_slot = DynamicDomSlot::new(sibling_slot.to_position());
             let shared_slot = own_slot.clone();
+            Transition::mark(self.id);
 
             let state = ComponentRenderState::Render {
                 bundle,
@@ -592,8 +729,27 @@ mod feat_csr {
                     ComponentRenderState::Ssr { .. } => {}
                 }
             }
+            Transition::mark(self.id);
             schedule_props_update(self.state.clone(), props, slot)
         }
+
+        /// Returns the parent element of the component and the node its content starts at, which
+        /// is `None` if the component is at the end of its parent.
+        pub(crate) fn dom_position(&self) -> Option<(Element, Option<Node>)> {
+            let state = self.state.try_borrow().ok()?;
+
+            match &state.as_ref()?.render_state {
+                ComponentRenderState::Render {
+                    parent, own_slot, ..
+                } => Some((parent.clone(), own_slot.next_sibling())),
+                #[cfg(feature = "hydration")]
+                ComponentRenderState::Hydration {
+                    parent, own_slot, ..
+                } => Some((parent.clone(), own_slot.next_sibling())),
+                #[cfg(feature = "ssr")]
+                ComponentRenderState::Ssr { .. } => None,
+            }
+        }
     }
 
     pub(crate) trait Scoped {
@@ -651,11 +807,13 @@ pub(crate) use feat_csr::*;
 #[cfg(feature = "hydration")]
 mod feat_hydration {
     use wasm_bindgen::JsCast;
-    use web_sys::{Element, HtmlScriptElement};
+    use web_sys::{Element, HtmlScriptElement, Node};
 
     use super::*;
     use crate::dom_bundle::{BSubtree, DomSlot, DynamicDomSlot, Fragment};
-    use crate::html::component::lifecycle::{ComponentRenderState, CreateRunner, RenderRunner};
+    use crate::html::component::lifecycle::{
+        ComponentRenderState, CreateRunner, DeferredHydration, RenderRunner,
+    };
     use crate::scheduler;
     use crate::virtual_dom::Collectable;
 
@@ -690,7 +848,24 @@ mod feat_hydration {
 
             let collectable = Collectable::for_component::<COMP>();
 
-            let mut fragment = Fragment::collect_between(fragment, &collectable, &parent);
+            let Some(mut fragment) = Fragment::collect_between(fragment, &collectable, &parent)
+            else {
+                // The markup of this component is missing. It is rendered on the client in place
+                // of the remaining markup, which is discarded with the component being hydrated.
+                let slot = DomSlot::create(
+                    fragment
+                        .front()
+                        .or_else(|| fragment.sibling_at_end())
+                        .cloned(),
+                );
+                let own_slot = self.mount_in_place(root, parent, slot, props);
+                if let Some(prev_next_sibling) = prev_next_sibling {
+                    prev_next_sibling.reassign(own_slot.to_position());
+                }
+                *prev_next_sibling = None;
+
+                return own_slot;
+            };
 
             let prepared_state = match fragment
                 .back()
@@ -740,6 +915,27 @@ mod feat_hydration {
             scheduler::start();
             shared_slot
         }
+
+        /// Delays the hydration of the component until [`Scope::resume_hydration`] is called.
+        ///
+        /// The server markup of the component stays in place without being hydrated.
+        /// `on_deferred` is called with its nodes once the first render has been delayed.
+        pub(crate) fn defer_hydration(&self, on_deferred: impl FnOnce(&[Node]) + 'static) {
+            DeferredHydration::defer(self.id, on_deferred);
+        }
+
+        /// Hydrates a component whose hydration has been delayed.
+        pub(crate) fn resume_hydration(&self) {
+            if DeferredHydration::resume(self.id) {
+                scheduler::push_component_render(
+                    self.id,
+                    Box::new(RenderRunner {
+                        state: self.state.clone(),
+                    }),
+                );
+                scheduler::start();
+            }
+        }
     }
 }
 
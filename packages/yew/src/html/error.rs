@@ -1,13 +1,69 @@
-use thiserror::Error;
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
 
 use crate::suspense::Suspension;
 
 /// Render Error.
-#[derive(Error, Debug, Clone, PartialEq)]
+///
+/// Errors that fail the rendering of a component are handed to the nearest
+/// [`ErrorBoundary`](crate::html::ErrorBoundary) and can be retrieved from
+/// [`CaughtError::error`](crate::html::CaughtError::error). Any error can be propagated out of a
+/// component with the `?` operator:
+///
+/// ```
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Answer() -> HtmlResult {
+///     let n: u32 = "42".parse()?;
+///
+///     Ok(html! { <div>{n}</div> })
+/// }
+/// ```
+///
+/// A [`Suspension`] is converted into [`RenderError::Suspended`], every other error into
+/// [`RenderError::Failed`]. To make this conversion possible, `RenderError` does not implement
+/// [`Error`] itself.
+#[derive(Debug)]
 pub enum RenderError {
     /// Component Rendering Suspended
-    #[error("component rendering is suspended.")]
-    Suspended(#[from] Suspension),
+    Suspended(Suspension),
+    /// Component Rendering Failed
+    Failed(Box<dyn Error>),
+}
+
+impl<E> From<E> for RenderError
+where
+    E: Error + 'static,
+{
+    fn from(error: E) -> Self {
+        let mut error = Some(error);
+
+        match (&mut error as &mut dyn Any).downcast_mut::<Option<Suspension>>() {
+            Some(m) => Self::Suspended(m.take().expect("error has been taken")),
+            None => Self::Failed(Box::new(error.expect("error has been taken"))),
+        }
+    }
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Suspended(_) => f.write_str("component rendering is suspended."),
+            Self::Failed(e) => write!(f, "component rendering failed: {e}"),
+        }
+    }
+}
+
+impl PartialEq for RenderError {
+    fn eq(&self, rhs: &Self) -> bool {
+        match (self, rhs) {
+            (Self::Suspended(l), Self::Suspended(r)) => l == r,
+            (Self::Failed(l), Self::Failed(r)) => std::ptr::addr_eq(&**l, &**r),
+            _ => false,
+        }
+    }
 }
 
 /// Render Result.
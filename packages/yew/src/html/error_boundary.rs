@@ -0,0 +1,480 @@
+//! This module contains the error boundary component.
+
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::callback::Callback;
+use crate::html::{Html, Properties};
+
+/// A failure caught by an [`ErrorBoundary`].
+#[derive(Debug, Clone)]
+pub struct CaughtError {
+    message: Rc<str>,
+    error: Option<Rc<dyn Error>>,
+    reset: Callback<()>,
+}
+
+impl CaughtError {
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    fn new(message: Rc<str>) -> Self {
+        Self {
+            message,
+            error: None,
+            reset: Callback::noop(),
+        }
+    }
+
+    /// Creates a [`CaughtError`] from the payload of a caught panic.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) fn from_panic(payload: Box<dyn std::any::Any + Send>) -> Self {
+        let message: Rc<str> = match payload.downcast::<String>() {
+            Ok(m) => m.as_str().into(),
+            Err(payload) => match payload.downcast_ref::<&'static str>() {
+                Some(m) => (*m).into(),
+                None => "Box<dyn Any>".into(),
+            },
+        };
+
+        Self::new(message)
+    }
+
+    /// Creates a [`CaughtError`] from an error returned by a component.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) fn from_error(error: Rc<dyn Error>) -> Self {
+        Self {
+            error: Some(error.clone()),
+            ..Self::new(error.to_string().into())
+        }
+    }
+
+    /// Returns the message describing the failure.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the error that failed the rendering of a component.
+    ///
+    /// This is `None` if the failure was caused by a panic or if the error was caught during
+    /// server-side rendering and the boundary has been hydrated since.
+    pub fn error(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.as_deref()
+    }
+
+    /// Resets the error boundary, which mounts its children again.
+    pub fn reset(&self) {
+        self.reset.emit(());
+    }
+}
+
+impl PartialEq for CaughtError {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.message, &rhs.message) && self.reset == rhs.reset
+    }
+}
+
+impl fmt::Display for CaughtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Properties for [ErrorBoundary].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct ErrorBoundaryProps {
+    /// The Children of the current Error Boundary.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Renders the Fallback UI once one of the children failed.
+    pub fallback: Callback<CaughtError, Html>,
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+mod feat_csr_ssr {
+    use std::any::Any;
+    use std::iter;
+
+    use super::*;
+    use crate::html::{AnyScope, Component, Context, Scope};
+    use crate::{component, html};
+
+    #[derive(Properties, PartialEq, Debug, Clone)]
+    pub(crate) struct BaseErrorBoundaryProps {
+        pub children: Html,
+        #[prop_or(None)]
+        pub fallback: Option<Callback<CaughtError, Html>>,
+    }
+
+    #[derive(Debug)]
+    pub(crate) enum BaseErrorBoundaryMsg {
+        Catch(CaughtError),
+        Reset,
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct BaseErrorBoundary {
+        pub(super) error: Option<CaughtError>,
+        /// Whether this boundary only marks the fallback of its parent boundary.
+        ///
+        /// Failures in a fallback are handled by the boundary above the one rendering it.
+        guards_fallback: bool,
+    }
+
+    impl Component for BaseErrorBoundary {
+        type Message = BaseErrorBoundaryMsg;
+        type Properties = BaseErrorBoundaryProps;
+
+        fn create(ctx: &Context<Self>) -> Self {
+            #[cfg(not(feature = "hydration"))]
+            let error = None;
+
+            // A boundary that failed during server-side rendering passes its error on as
+            // prepared state, so hydration starts with the fallback rendered by the server.
+            #[cfg(feature = "hydration")]
+            let error = ctx.prepared_state().map(|m| CaughtError {
+                reset: ctx.link().callback(|_| BaseErrorBoundaryMsg::Reset),
+                ..CaughtError::new(unescape_message(m).into())
+            });
+
+            Self {
+                error,
+                guards_fallback: ctx.props().fallback.is_none(),
+            }
+        }
+
+        fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                BaseErrorBoundaryMsg::Catch(mut m) => {
+                    // Other failures of a subtree that is about to be unmounted are ignored.
+                    if self.error.is_some() {
+                        return false;
+                    }
+
+                    m.reset = ctx.link().callback(|_| BaseErrorBoundaryMsg::Reset);
+                    self.error = Some(m);
+
+                    true
+                }
+                BaseErrorBoundaryMsg::Reset => self.error.take().is_some(),
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let BaseErrorBoundaryProps { children, fallback } = ctx.props();
+
+            match (fallback, &self.error) {
+                (Some(fallback), Some(error)) => Self::render_fallback(fallback, error.clone()),
+                _ => children.clone(),
+            }
+        }
+    }
+
+    impl BaseErrorBoundary {
+        pub(super) fn render_fallback(
+            fallback: &Callback<CaughtError, Html>,
+            error: CaughtError,
+        ) -> Html {
+            let fallback = fallback.emit(error);
+
+            html! {
+                <BaseErrorBoundary>
+                    {fallback}
+                </BaseErrorBoundary>
+            }
+        }
+
+        pub(crate) fn catch(scope: &Scope<Self>, error: CaughtError) {
+            scope.send_message(BaseErrorBoundaryMsg::Catch(error));
+        }
+
+        /// Finds the boundary responsible for failures of the component at `scope`.
+        fn find(scope: &AnyScope) -> Option<Scope<Self>> {
+            let mut skip = 0;
+
+            for m in iter::successors(scope.get_parent(), |m| m.get_parent()) {
+                let Some(m) = m.try_downcast::<Self>() else {
+                    continue;
+                };
+
+                if m.get_component().is_some_and(|m| m.guards_fallback) {
+                    // The next boundary is the one rendering this fallback.
+                    skip += 1;
+                } else if skip > 0 {
+                    skip -= 1;
+                } else {
+                    return Some(m);
+                }
+            }
+
+            None
+        }
+    }
+
+    /// Hands a panic raised by the component at `scope` to its nearest error boundary.
+    ///
+    /// The panic is resumed if the component is not wrapped in an [`ErrorBoundary`].
+    pub(crate) fn catch_panic(scope: &AnyScope, payload: Box<dyn Any + Send>) {
+        match BaseErrorBoundary::find(scope) {
+            Some(m) => BaseErrorBoundary::catch(&m, CaughtError::from_panic(payload)),
+            None => std::panic::resume_unwind(payload),
+        }
+    }
+
+    /// Hands an error returned by the component at `scope` to its nearest error boundary.
+    ///
+    /// # Panics
+    ///
+    /// If the component is not wrapped in an [`ErrorBoundary`].
+    pub(crate) fn catch_error(scope: &AnyScope, error: Box<dyn Error>) {
+        match BaseErrorBoundary::find(scope) {
+            Some(m) => BaseErrorBoundary::catch(&m, CaughtError::from_error(error.into())),
+            None => panic!("component rendering failed: {error}"),
+        }
+    }
+
+    #[cfg(feature = "hydration")]
+    fn unescape_message(s: &str) -> String {
+        s.replace("&lt;", "<").replace("&amp;", "&")
+    }
+
+    /// Catches failures in its children and shows a fallback UI instead.
+    ///
+    /// Errors returned from a descendant as
+    /// [`RenderError::Failed`](crate::html::RenderError::Failed) unmount the children of the
+    /// nearest boundary and render its `fallback` in their place. The fallback can mount the
+    /// children again with [`CaughtError::reset`].
+    ///
+    /// During server-side rendering, the output of the children is replaced by the fallback and
+    /// hydration starts from the fallback as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics raised while creating, updating, rendering or viewing a descendant are caught the
+    /// same way, but only on targets where panics unwind, such as the server. Targets and profiles
+    /// built with `panic = "abort"` abort before the boundary is notified. This includes
+    /// `wasm32-unknown-unknown` by default, so in the browser only returned errors are caught.
+    #[component]
+    pub fn ErrorBoundary(props: &ErrorBoundaryProps) -> Html {
+        let ErrorBoundaryProps { children, fallback } = props.clone();
+        let fallback = Some(fallback);
+
+        html! {
+            <BaseErrorBoundary {fallback}>
+                {children}
+            </BaseErrorBoundary>
+        }
+    }
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub use feat_csr_ssr::*;
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::fmt::Write;
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::feat_ssr::VTagKind;
+    use crate::html::{AnyScope, Scope};
+    use crate::platform::fmt::{self, BufWriter};
+
+    impl BaseErrorBoundary {
+        /// Renders the children of a boundary, which are replaced by the fallback if any of them
+        /// fails.
+        ///
+        /// The children are buffered as their output can only be written once the entire subtree
+        /// has been rendered.
+        pub(crate) async fn render_into_stream(
+            scope: &Scope<Self>,
+            props: &BaseErrorBoundaryProps,
+            children: Html,
+            w: &mut BufWriter,
+            hydratable: bool,
+            parent_vtag_kind: VTagKind,
+        ) {
+            let self_any_scope = AnyScope::from(scope.clone());
+
+            let Some(ref fallback) = props.fallback else {
+                children
+                    .render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
+                    .await;
+                return;
+            };
+
+            let (mut buf_w, buf_r) = fmt::buffer();
+            children
+                .render_into_stream(&mut buf_w, &self_any_scope, hydratable, parent_vtag_kind)
+                .await;
+            drop(buf_w);
+            let buf: String = buf_r.collect().await;
+
+            let error = scope.get_component().and_then(|m| m.error.clone());
+            match error {
+                None => {
+                    let _ = w.write_str(&buf);
+                }
+                Some(error) => {
+                    let message = error.message().replace('&', "&amp;").replace('<', "&lt;");
+
+                    Self::render_fallback(fallback, error)
+                        .render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
+                        .await;
+
+                    // Passed on as prepared state, so hydration starts with the fallback.
+                    if hydratable {
+                        let _ = w.write_str(r#"<script type="application/x-yew-comp-state">"#);
+                        let _ = w.write_str(&message);
+                        let _ = w.write_str(r#"</script>"#);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+mod feat_no_csr_ssr {
+    use super::*;
+    use crate::component;
+
+    /// Catches failures in its children and shows a fallback UI instead.
+    #[component]
+    pub fn ErrorBoundary(_props: &ErrorBoundaryProps) -> Html {
+        Html::default()
+    }
+}
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+pub use feat_no_csr_ssr::*;
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::LocalServerRenderer as ServerRenderer;
+    use crate::prelude::*;
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct ChildProps {
+        name: String,
+        #[prop_or_default]
+        fail: bool,
+    }
+
+    #[component]
+    fn Child(props: &ChildProps) -> Html {
+        if props.fail {
+            panic!("failed to greet {}", props.name);
+        }
+
+        html! { <div>{"Hello, "}{&props.name}{"!"}</div> }
+    }
+
+    fn fallback() -> Callback<CaughtError, Html> {
+        Callback::from(|e: CaughtError| html! { <p>{e.message()}</p> })
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_error_boundary_renders_children() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <Child name="Jane" />
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Hello, Jane!</div>");
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_error_boundary_renders_fallback() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <Child name="Jane" />
+                    <Child name="John" fail=true />
+                </ErrorBoundary>
+                <Child name="Josh" />
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<p>failed to greet John</p><div>Hello, Josh!</div>");
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_error_boundary_fallback_failure_is_handled_by_outer_boundary() {
+        #[component]
+        fn Comp() -> Html {
+            let failing_fallback =
+                Callback::from(|_: CaughtError| html! { <Child name="Jane" fail=true /> });
+
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <ErrorBoundary fallback={failing_fallback}>
+                        <Child name="John" fail=true />
+                    </ErrorBoundary>
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<p>failed to greet Jane</p>");
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_error_boundary_catches_render_errors() {
+        #[component]
+        fn Parse() -> HtmlResult {
+            let n: u32 = "forty-two".parse()?;
+
+            Ok(html! { <div>{n}</div> })
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            let fallback = Callback::from(|e: CaughtError| {
+                let has_error = e.error().is_some();
+                html! { <p>{e.message()}{" "}{has_error}</p> }
+            });
+
+            html! {
+                <ErrorBoundary {fallback}>
+                    <Parse />
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<p>invalid digit found in string true</p>");
+    }
+}
@@ -4,6 +4,7 @@ mod classes;
 mod component;
 mod conversion;
 mod error;
+mod error_boundary;
 mod listener;
 
 use std::cell::RefCell;
@@ -13,6 +14,7 @@ pub use classes::*;
 pub use component::*;
 pub use conversion::*;
 pub use error::*;
+pub use error_boundary::*;
 pub use listener::*;
 use wasm_bindgen::JsValue;
 use web_sys::{Element, Node};
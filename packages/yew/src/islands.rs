@@ -0,0 +1,364 @@
+//! This module contains the [`Island`] component and the functions to hydrate islands on the
+//! client.
+//!
+//! Islands are interactive components in an otherwise static page rendered by the server. Each
+//! island is hydrated as an independent application, so the rest of the page does not have to be
+//! hydrated.
+//!
+//! Each component used as an island implements [`IslandComponent`], which names it in the markup.
+//! On the server, wrap the interactive components in an [`Island`]. On the client, register each
+//! component used as an island with [`register`] and call [`hydrate_all`]:
+//!
+//! ```no_run
+//! # use serde::{Deserialize, Serialize};
+//! # use yew::prelude::*;
+//! #[derive(Properties, PartialEq, Serialize, Deserialize)]
+//! struct CounterProps {
+//!     initial: u32,
+//! }
+//!
+//! #[component]
+//! fn Counter(props: &CounterProps) -> Html {
+//!     // ...
+//!     # Html::default()
+//! }
+//!
+//! impl yew::islands::IslandComponent for Counter {
+//!     const NAME: &'static str = "counter";
+//! }
+//!
+//! // Rendered by the server.
+//! #[component]
+//! fn Page() -> Html {
+//!     html! {
+//!         <article>
+//!             <p>{"Mostly static content."}</p>
+//!             <yew::islands::Island<Counter> initial={3} />
+//!         </article>
+//!     }
+//! }
+//!
+//! // Run on the client.
+//! # #[cfg(feature = "hydration")]
+//! fn main() {
+//!     yew::islands::register::<Counter>();
+//!     yew::islands::hydrate_all();
+//! }
+//! # #[cfg(not(feature = "hydration"))]
+//! # fn main() {}
+//! ```
+
+use std::marker::PhantomData;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::html::{BaseComponent, Component, Context, Html};
+use crate::virtual_dom::{VComp, VNode, VTag};
+
+/// A component that can be rendered as an [`Island`].
+pub trait IslandComponent: BaseComponent {
+    /// The name of the component in the markup rendered by the server.
+    ///
+    /// It has to be the same on the server and the client, and unique among the components used
+    /// as islands.
+    const NAME: &'static str;
+}
+
+/// The tag name of the element wrapping an island.
+const ISLAND_TAG: &str = "yew-island";
+/// The attribute holding the name of the component of an island.
+const NAME_ATTR: &str = "data-island";
+/// The attribute holding the encoded properties of an island.
+#[cfg(any(feature = "ssr", feature = "hydration"))]
+const PROPS_ATTR: &str = "data-props";
+
+/// Renders the component `C` as an island.
+///
+/// The island accepts the properties of `C`. When rendered on the server, the component is
+/// wrapped in a `<yew-island>` element holding its name and properties, which [`hydrate_all`]
+/// uses to hydrate it on the client.
+///
+/// The properties are encoded with the [codec](crate::ServerRenderer::state_codec) of the server
+/// renderer. An island whose properties cannot be encoded is rendered without them, logs the
+/// error and is not hydrated.
+///
+/// The page has to be rendered with hydratable markup, which is the default of the server
+/// renderer.
+#[derive(Debug)]
+pub struct Island<C> {
+    _marker: PhantomData<C>,
+}
+
+impl<C> Component for Island<C>
+where
+    C: IslandComponent,
+    C::Properties: Serialize + DeserializeOwned,
+{
+    type Message = ();
+    type Properties = C::Properties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let mut tag = VTag::new(ISLAND_TAG);
+        tag.add_attribute("style", "display: contents");
+        tag.add_attribute(NAME_ATTR, C::NAME);
+
+        // The properties are only read from the markup rendered by the server.
+        #[cfg(feature = "ssr")]
+        if ctx.creation_mode() == crate::html::RenderMode::Ssr {
+            let scope = crate::html::AnyScope::from(ctx.link().clone());
+            let codec = crate::feat_ssr::RenderState::find(&scope)
+                .map(|m| m.state_codec)
+                .unwrap_or_default();
+
+            match codec.encode(ctx.props()) {
+                Ok(m) => tag.add_attribute(PROPS_ATTR, m),
+                Err(e) => tracing::error!(
+                    "island of component {} is rendered without its properties: {e}",
+                    C::NAME
+                ),
+            }
+        }
+
+        tag.add_child(VComp::new::<C>(ctx.shared_props().clone(), None).into());
+
+        VNode::from(tag)
+    }
+}
+
+#[cfg(feature = "hydration")]
+mod feat_hydration {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use wasm_bindgen::JsCast;
+    use web_sys::Element;
+
+    use super::*;
+    use crate::Renderer;
+    use crate::functional::StateCodec;
+    use crate::platform::spawn_local;
+
+    type HydrateIsland = fn(Element, String, StateCodec);
+
+    thread_local! {
+        static REGISTRY: RefCell<HashMap<&'static str, HydrateIsland>> =
+            RefCell::new(HashMap::new());
+    }
+
+    fn hydrate_island<C>(root: Element, props: String, codec: StateCodec)
+    where
+        C: IslandComponent,
+        C::Properties: DeserializeOwned,
+    {
+        // An island with malformed properties is left as it is, so the other islands are still
+        // hydrated.
+        spawn_local(async move {
+            match codec.decode::<C::Properties>(&props).await {
+                Ok(props) => {
+                    Renderer::<C>::with_root_and_props(root, props)
+                        .state_codec(codec)
+                        .hydrate();
+                }
+                Err(e) => tracing::error!("island of component {} is not hydrated: {e}", C::NAME),
+            }
+        });
+    }
+
+    /// Registers the component `C`, so its islands are hydrated by [`hydrate_all`].
+    pub fn register<C>()
+    where
+        C: IslandComponent,
+        C::Properties: DeserializeOwned,
+    {
+        REGISTRY.with(|m| m.borrow_mut().insert(C::NAME, hydrate_island::<C>));
+    }
+
+    /// Hydrates the islands in the document rendered by [`Island`].
+    ///
+    /// Each island is hydrated as an independent application with the properties it has been
+    /// rendered with on the server, decoded with the default [`StateCodec`]. Islands nested in
+    /// other islands are hydrated with them, and islands of components that have not been
+    /// [registered](register) or whose properties fail to decode are left as they are.
+    ///
+    /// The properties are decoded asynchronously, so the islands are hydrated once the current
+    /// task has finished. Islands that have been hydrated are skipped when this function is
+    /// called again.
+    pub fn hydrate_all() {
+        hydrate_all_with_codec(StateCodec::default());
+    }
+
+    /// Hydrates the islands in the document rendered by [`Island`], decoding their properties
+    /// with `codec`.
+    ///
+    /// The codec has to match the [codec](crate::ServerRenderer::state_codec) of the server
+    /// renderer. It is also used to decode the prepared states of the islands. See
+    /// [`hydrate_all`].
+    pub fn hydrate_all_with_codec(codec: StateCodec) {
+        let islands = match gloo::utils::document()
+            .query_selector_all(&format!("{ISLAND_TAG}[{PROPS_ATTR}]"))
+        {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::error!("failed to query islands: {e:?}");
+                return;
+            }
+        };
+
+        for i in 0..islands.length() {
+            let Some(root) = islands.get(i).and_then(|m| m.dyn_into::<Element>().ok()) else {
+                continue;
+            };
+
+            let is_nested = root
+                .parent_element()
+                .and_then(|m| m.closest(ISLAND_TAG).ok().flatten())
+                .is_some();
+            if is_nested {
+                continue;
+            }
+
+            let name = root.get_attribute(NAME_ATTR).unwrap_or_default();
+            let Some(hydrate) = REGISTRY.with(|m| m.borrow().get(name.as_str()).copied()) else {
+                tracing::warn!("island of unregistered component {name} is not hydrated");
+                continue;
+            };
+
+            // Claims the island, so it is not hydrated twice.
+            let props = root.get_attribute(PROPS_ATTR).unwrap_or_default();
+            root.remove_attribute(PROPS_ATTR).unwrap();
+
+            hydrate(root, props, codec);
+        }
+    }
+}
+#[cfg(feature = "hydration")]
+pub use feat_hydration::*;
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use base64ct::{Base64, Encoding};
+    use serde::Deserialize;
+    use tokio::test;
+
+    use super::*;
+    use crate::ServerRenderer;
+    use crate::prelude::*;
+
+    #[derive(Properties, PartialEq, Debug, Serialize, Deserialize)]
+    struct CounterProps {
+        initial: u32,
+    }
+
+    #[component]
+    fn Counter(props: &CounterProps) -> Html {
+        html! { <button>{props.initial}</button> }
+    }
+
+    impl IslandComponent for Counter {
+        const NAME: &'static str = "counter";
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_island_markup() {
+        #[component]
+        fn Page() -> Html {
+            html! {
+                <article>
+                    <p>{"static"}</p>
+                    <Island<Counter> initial={3} />
+                </article>
+            }
+        }
+
+        let s = ServerRenderer::<Page>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        let props = s
+            .split(r#"data-props=""#)
+            .nth(1)
+            .and_then(|m| m.split('"').next())
+            .unwrap();
+        let props = Base64::decode_vec(props).unwrap();
+        let (props, _) = bincode::serde::decode_from_slice::<CounterProps, _>(
+            &props,
+            bincode::config::standard(),
+        )
+        .unwrap();
+        assert_eq!(props, CounterProps { initial: 3 });
+
+        assert!(s.starts_with(
+            r#"<article><p>static</p><yew-island style="display: contents" data-island="counter" data-props=""#
+        ));
+        assert!(s.ends_with("<button>3</button></yew-island></article>"));
+    }
+
+    #[cfg(feature = "json")]
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_island_props_use_state_codec() {
+        #[component]
+        fn Page() -> Html {
+            html! { <Island<Counter> initial={3} /> }
+        }
+
+        let s = ServerRenderer::<Page>::new()
+            .hydratable(false)
+            .state_codec(crate::functional::StateCodec::Json)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<yew-island style="display: contents" data-island="counter" data-props="{&quot;initial&quot;:3}"><button>3</button></yew-island>"#
+        );
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_unencodable_island_props_are_not_sent() {
+        #[derive(Properties, PartialEq, Deserialize)]
+        struct UnencodableProps {}
+
+        impl Serialize for UnencodableProps {
+            fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom("cannot be encoded"))
+            }
+        }
+
+        #[component]
+        fn Unencodable(_props: &UnencodableProps) -> Html {
+            html! { <p>{"rendered"}</p> }
+        }
+
+        impl IslandComponent for Unencodable {
+            const NAME: &'static str = "unencodable";
+        }
+
+        #[component]
+        fn Page() -> Html {
+            html! { <Island<Unencodable> /> }
+        }
+
+        let s = ServerRenderer::<Page>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<yew-island style="display: contents" data-island="unencodable"><p>rendered</p></yew-island>"#
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! This module contains the [`LazyHydrate`] component, which delays the hydration of its
+//! children.
+
+#[cfg(feature = "hydration")]
+use std::cell::RefCell;
+#[cfg(feature = "hydration")]
+use std::rc::Rc;
+
+use crate::AttrValue;
+use crate::html::{Component, Context, Html, Properties};
+
+/// The event that makes a [`LazyHydrate`] hydrate its children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HydrateWhen {
+    /// When any of the elements rendered by the server becomes visible in the viewport.
+    Visible,
+    /// When the browser is idle.
+    Idle,
+    /// When an event of the given type, such as `"click"` or `"focusin"`, is dispatched on any
+    /// of the elements rendered by the server.
+    ///
+    /// The event itself is not handled by the hydrated children.
+    Interaction(AttrValue),
+}
+
+/// Properties for [LazyHydrate].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct LazyHydrateProps {
+    /// When the children are hydrated.
+    pub when: HydrateWhen,
+    /// The children to hydrate.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Delays the hydration of its children.
+///
+/// During hydration, the markup rendered by the server for the children stays in place but is
+/// not hydrated, so it does not react to events, until the event in
+/// [`when`](LazyHydrateProps::when) happens. This saves work on the main thread for parts of the
+/// page that the user is unlikely to interact with right away.
+///
+/// When rendered on the server or without hydration, the children are rendered right away.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # #[component]
+/// # fn Comments() -> Html { Html::default() }
+/// #[component]
+/// fn Article() -> Html {
+///     html! {
+///         <article>
+///             <p>{"..."}</p>
+///             <LazyHydrate when={HydrateWhen::Visible}>
+///                 <Comments />
+///             </LazyHydrate>
+///         </article>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct LazyHydrate {
+    #[cfg(feature = "hydration")]
+    trigger: Rc<RefCell<Option<feat_hydration::Trigger>>>,
+}
+
+impl Component for LazyHydrate {
+    type Message = ();
+    type Properties = LazyHydrateProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        #[cfg(feature = "hydration")]
+        {
+            use crate::html::RenderMode;
+
+            let trigger: Rc<RefCell<_>> = Rc::default();
+
+            if ctx.creation_mode() == RenderMode::Hydration {
+                let scope = ctx.link().clone();
+                let when = ctx.props().when.clone();
+                let trigger = Rc::clone(&trigger);
+
+                ctx.link().defer_hydration(move |nodes| {
+                    *trigger.borrow_mut() = feat_hydration::Trigger::new(&when, nodes, scope);
+                });
+            }
+
+            Self { trigger }
+        }
+
+        #[cfg(not(feature = "hydration"))]
+        {
+            let _ = ctx;
+            Self {}
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        ctx.props().children.clone()
+    }
+
+    #[cfg(feature = "hydration")]
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            self.trigger.borrow_mut().take();
+        }
+    }
+}
+
+#[cfg(feature = "hydration")]
+mod feat_hydration {
+    use gloo::events::EventListener;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen::prelude::Closure;
+    use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, Node};
+
+    use super::*;
+    use crate::html::Scope;
+
+    /// Listens for the event that starts the hydration of a [`LazyHydrate`].
+    pub(super) enum Trigger {
+        Visible {
+            observer: IntersectionObserver,
+            _callback: Closure<dyn FnMut(js_sys::Array)>,
+        },
+        Idle {
+            handle: u32,
+            _callback: Closure<dyn FnMut()>,
+        },
+        Interaction {
+            _listeners: Vec<EventListener>,
+        },
+    }
+
+    impl std::fmt::Debug for Trigger {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("Trigger<_>")
+        }
+    }
+
+    impl Trigger {
+        /// Starts listening for `when` on the server markup in `nodes`.
+        ///
+        /// Hydrates right away and returns `None` if the event cannot be listened for.
+        pub fn new(when: &HydrateWhen, nodes: &[Node], scope: Scope<LazyHydrate>) -> Option<Self> {
+            let elements = nodes
+                .iter()
+                .filter_map(|m| m.dyn_ref::<Element>())
+                .collect::<Vec<_>>();
+
+            let trigger = match when {
+                HydrateWhen::Visible if !elements.is_empty() => {
+                    let scope = scope.clone();
+                    let callback =
+                        Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                            let visible = entries.iter().any(|m| {
+                                m.unchecked_into::<IntersectionObserverEntry>()
+                                    .is_intersecting()
+                            });
+                            if visible {
+                                scope.resume_hydration();
+                            }
+                        });
+
+                    IntersectionObserver::new(callback.as_ref().unchecked_ref())
+                        .ok()
+                        .map(|observer| {
+                            for element in elements {
+                                observer.observe(element);
+                            }
+
+                            Self::Visible {
+                                observer,
+                                _callback: callback,
+                            }
+                        })
+                }
+                HydrateWhen::Idle => {
+                    let scope = scope.clone();
+                    let callback = Closure::<dyn FnMut()>::new(move || scope.resume_hydration());
+
+                    // Not every browser supports `requestIdleCallback`.
+                    gloo::utils::window()
+                        .request_idle_callback(callback.as_ref().unchecked_ref())
+                        .ok()
+                        .map(|handle| Self::Idle {
+                            handle,
+                            _callback: callback,
+                        })
+                }
+                HydrateWhen::Interaction(event_type) if !elements.is_empty() => {
+                    let listeners = elements
+                        .into_iter()
+                        .map(|element| {
+                            let scope = scope.clone();
+                            EventListener::new(element, event_type.to_string(), move |_| {
+                                scope.resume_hydration()
+                            })
+                        })
+                        .collect();
+
+                    Some(Self::Interaction {
+                        _listeners: listeners,
+                    })
+                }
+                _ => None,
+            };
+
+            if trigger.is_none() {
+                scope.resume_hydration();
+            }
+
+            trigger
+        }
+    }
+
+    impl Drop for Trigger {
+        fn drop(&mut self) {
+            match self {
+                Self::Visible { observer, .. } => observer.disconnect(),
+                Self::Idle { handle, .. } => gloo::utils::window().cancel_idle_callback(*handle),
+                Self::Interaction { .. } => {}
+            }
+        }
+    }
+}
@@ -275,8 +275,12 @@ pub mod context;
 #[cfg(feature = "csr")]
 mod dom_bundle;
 pub mod functional;
+pub mod head;
 pub mod html;
+pub mod islands;
+pub mod lazy_hydrate;
 pub mod platform;
+pub mod render_cache;
 pub mod scheduler;
 mod sealed;
 #[cfg(feature = "ssr")]
@@ -312,6 +316,8 @@ pub mod events {
 
 #[cfg(feature = "csr")]
 pub use crate::app_handle::AppHandle;
+#[cfg(feature = "hydration")]
+pub use crate::dom_bundle::{HydrationMismatch, set_hydration_mismatch_hook};
 #[cfg(feature = "csr")]
 pub use crate::renderer::{Renderer, set_custom_panic_hook};
 
@@ -331,10 +337,12 @@ pub mod prelude {
     pub use crate::context::{ContextHandle, ContextProvider};
     pub use crate::events::*;
     pub use crate::functional::*;
+    pub use crate::head::Head;
     pub use crate::html::{
-        BaseComponent, Children, ChildrenWithProps, Classes, Component, Context, Html, HtmlResult,
-        NodeRef, Properties, create_portal,
+        BaseComponent, CaughtError, Children, ChildrenWithProps, Classes, Component, Context,
+        ErrorBoundary, Html, HtmlResult, NodeRef, Properties, create_portal,
     };
+    pub use crate::lazy_hydrate::{HydrateWhen, LazyHydrate};
     pub use crate::macros::{classes, html, html_nested};
     pub use crate::suspense::Suspense;
     pub use crate::virtual_dom::AttrValue;
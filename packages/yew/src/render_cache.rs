@@ -0,0 +1,438 @@
+//! This module contains the [`Cached`] component and the caches of the markup rendered by it
+//! during server-side rendering.
+//!
+//! Components that do not depend on the data of a request, such as footers or navigation menus,
+//! render the same markup for the same properties. When such a component implements
+//! [`Cacheable`] and is rendered with [`Cached`], its markup is rendered once and replayed from
+//! the cache set with [`ServerRenderer::render_cache`](crate::ServerRenderer::render_cache)
+//! afterwards:
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use yew::prelude::*;
+//! use yew::render_cache::{Cacheable, Cached, LruRenderCache};
+//!
+//! #[derive(Properties, PartialEq)]
+//! struct FooterProps {
+//!     lang: AttrValue,
+//! }
+//!
+//! #[component]
+//! fn Footer(props: &FooterProps) -> Html {
+//!     // ...
+//!     # Html::default()
+//! }
+//!
+//! impl Cacheable for Footer {
+//!     fn cache_key(props: &FooterProps) -> Option<String> {
+//!         Some(props.lang.to_string())
+//!     }
+//! }
+//!
+//! #[component]
+//! fn App() -> Html {
+//!     html! {
+//!         <Cached<Footer> lang="en" />
+//!     }
+//! }
+//!
+//! # #[cfg(feature = "ssr")]
+//! # async fn render() {
+//! let cache = Arc::new(LruRenderCache::new(128));
+//!
+//! // The footer is only rendered by the first render.
+//! for _ in 0..2 {
+//!     let html = yew::ServerRenderer::<App>::new()
+//!         .render_cache(cache.clone())
+//!         .render()
+//!         .await;
+//! }
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::html::{BaseComponent, Component, Context, Html};
+use crate::virtual_dom::VComp;
+
+/// A component whose markup can be cached during server-side rendering.
+///
+/// See the [module documentation](self).
+pub trait Cacheable: BaseComponent {
+    /// Returns the key the markup rendered with `props` is cached with.
+    ///
+    /// The markup is cached for the component and the key, so components rendering different
+    /// markup must return different keys. If `None` is returned, the component is rendered
+    /// without the cache.
+    fn cache_key(props: &Self::Properties) -> Option<String>;
+}
+
+/// Renders the component `C` with its markup cached during server-side rendering.
+///
+/// The component accepts the properties of `C`. On the client, `C` is rendered as if it was not
+/// wrapped, so the cached markup can be hydrated.
+///
+/// The markup is replayed as it has been written by the first render with the same key at the
+/// same position in the tree, including the hydration markers, the states prepared by the
+/// components and the ids returned by [`use_id`](crate::functional::use_id). Other effects of the
+/// render are not replayed: the tags rendered by [`Head`](crate::head::Head) and the status and
+/// headers set with [`use_server_response`](crate::functional::use_server_response) are lost when
+/// the markup is replayed, so cached components should not use them.
+#[derive(Debug)]
+pub struct Cached<C> {
+    _marker: PhantomData<C>,
+}
+
+impl<C> Component for Cached<C>
+where
+    C: Cacheable,
+{
+    type Message = ();
+    type Properties = C::Properties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        #[cfg(feature = "ssr")]
+        let comp = VComp::new_cached::<C>(
+            ctx.shared_props().clone(),
+            C::cache_key(ctx.props()).map(Into::into),
+        );
+        #[cfg(not(feature = "ssr"))]
+        let comp = VComp::new::<C>(ctx.shared_props().clone(), None);
+
+        comp.into()
+    }
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::collections::{BTreeMap, HashMap};
+    use std::fmt;
+    use std::sync::{Arc, Mutex, PoisonError};
+
+    use futures::FutureExt;
+
+    use crate::feat_ssr::{RenderState, VTagKind};
+    use crate::html::AnyScope;
+    use crate::suspense::Deadline;
+
+    /// A cache of the markup rendered by [`Cached`](super::Cached) components.
+    ///
+    /// The cache is shared by the renders it is set for, which may run on different threads.
+    pub trait RenderCache: fmt::Debug + Send + Sync {
+        /// Returns the markup cached with `key`.
+        fn get(&self, key: &str) -> Option<Arc<str>>;
+
+        /// Caches `html` with `key`.
+        fn insert(&self, key: String, html: Arc<str>);
+    }
+
+    #[derive(Debug, Default)]
+    struct LruEntries {
+        entries: HashMap<String, (Arc<str>, u64)>,
+        // The keys of the entries, by the time they have last been used.
+        used: BTreeMap<u64, String>,
+        now: u64,
+    }
+
+    /// A [`RenderCache`] in memory, evicting the least recently used entries once it is full.
+    #[derive(Debug)]
+    pub struct LruRenderCache {
+        capacity: usize,
+        inner: Mutex<LruEntries>,
+    }
+
+    impl LruRenderCache {
+        /// Creates a cache holding up to `capacity` entries.
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                capacity,
+                inner: Mutex::default(),
+            }
+        }
+
+        /// Returns the number of cached entries.
+        pub fn len(&self) -> usize {
+            self.lock().entries.len()
+        }
+
+        /// Returns `true` if no entries are cached.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        fn lock(&self) -> std::sync::MutexGuard<'_, LruEntries> {
+            // The entries are valid even if a thread has panicked while holding the lock.
+            self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    impl Default for LruRenderCache {
+        /// Creates a cache holding up to 1024 entries.
+        fn default() -> Self {
+            Self::new(1024)
+        }
+    }
+
+    impl RenderCache for LruRenderCache {
+        fn get(&self, key: &str) -> Option<Arc<str>> {
+            let mut inner = self.lock();
+            let LruEntries { entries, used, now } = &mut *inner;
+
+            let (html, last_used) = entries.get_mut(key)?;
+            *now += 1;
+            let key = used.remove(last_used).expect("entry without last use");
+            used.insert(*now, key);
+            *last_used = *now;
+
+            Some(html.clone())
+        }
+
+        fn insert(&self, key: String, html: Arc<str>) {
+            if self.capacity == 0 {
+                return;
+            }
+
+            let mut inner = self.lock();
+            let LruEntries { entries, used, now } = &mut *inner;
+
+            *now += 1;
+            if let Some((_, last_used)) = entries.insert(key.clone(), (html, *now)) {
+                used.remove(&last_used);
+            }
+            used.insert(*now, key);
+
+            while entries.len() > self.capacity {
+                let Some((_, key)) = used.pop_first() else {
+                    break;
+                };
+                entries.remove(&key);
+            }
+        }
+    }
+
+    /// The entry of a component in the cache of a render.
+    pub(crate) struct CacheEntry {
+        cache: Arc<dyn RenderCache>,
+        key: String,
+        deadline: Option<Deadline>,
+    }
+
+    impl CacheEntry {
+        /// Finds the entry of the component named `component` at `scope` with `key` in the cache
+        /// of the render the component belongs to.
+        ///
+        /// Returns `None` if the render has no cache, or if the render streams suspended
+        /// components out of order, as their markup is not written in place.
+        pub fn find(
+            scope: &AnyScope,
+            component: &str,
+            key: &str,
+            hydratable: bool,
+            parent_vtag_kind: VTagKind,
+        ) -> Option<Self> {
+            let state = RenderState::find(scope)?;
+            if state.deferred.is_some() {
+                return None;
+            }
+            let cache = state.render_cache.clone()?;
+
+            // The markup differs for hydratable renders, the codecs of prepared states and the
+            // escaping of the tag it is rendered in. The ids returned by `use_id` are derived from
+            // the position of the component in the tree.
+            let hydratable = if hydratable { "hydratable" } else { "static" };
+            let vtag_kind = match parent_vtag_kind {
+                VTagKind::Style => "style",
+                VTagKind::Script => "script",
+                VTagKind::Other => "other",
+            };
+            let position = scope
+                .position
+                .path()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("-");
+            let key = format!(
+                "{component}:{hydratable}:{}:{vtag_kind}:{position}:{key}",
+                state.state_codec
+            );
+
+            Some(Self {
+                cache,
+                key,
+                deadline: state.deadline.clone(),
+            })
+        }
+
+        /// Returns the cached markup.
+        pub fn get(&self) -> Option<Arc<str>> {
+            self.cache.get(&self.key)
+        }
+
+        /// Caches the markup rendered by the component.
+        ///
+        /// The markup is not cached if the timeout of the render has elapsed, as suspended
+        /// components may have been written with their fallback.
+        pub fn insert(self, html: String) {
+            let timed_out = self
+                .deadline
+                .is_some_and(|m| m.reached().now_or_never().is_some());
+            if !timed_out {
+                self.cache.insert(self.key, html.into());
+            }
+        }
+    }
+}
+#[cfg(feature = "ssr")]
+pub(crate) use feat_ssr::CacheEntry;
+#[cfg(feature = "ssr")]
+pub use feat_ssr::{LruRenderCache, RenderCache};
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use std::cell::Cell;
+    use std::sync::Arc;
+
+    use tokio::test;
+
+    use super::*;
+    use crate::LocalServerRenderer;
+    use crate::prelude::*;
+
+    thread_local! {
+        static RENDERS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct FooterProps {
+        lang: AttrValue,
+    }
+
+    #[component]
+    fn Footer(props: &FooterProps) -> Html {
+        RENDERS.with(|m| m.set(m.get() + 1));
+
+        html! { <footer>{props.lang.clone()}</footer> }
+    }
+
+    impl Cacheable for Footer {
+        fn cache_key(props: &FooterProps) -> Option<String> {
+            Some(props.lang.to_string())
+        }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct AppProps {
+        lang: AttrValue,
+    }
+
+    #[component]
+    fn App(props: &AppProps) -> Html {
+        html! { <Cached<Footer> lang={props.lang.clone()} /> }
+    }
+
+    async fn render(cache: &Arc<LruRenderCache>, lang: &'static str, hydratable: bool) -> String {
+        LocalServerRenderer::<App>::with_props(AppProps { lang: lang.into() })
+            .hydratable(hydratable)
+            .render_cache(cache.clone())
+            .render()
+            .await
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_cached_markup_is_replayed() {
+        let cache = Arc::new(LruRenderCache::default());
+
+        let first = render(&cache, "en", true).await;
+        assert_eq!(RENDERS.with(Cell::get), 1);
+        assert!(first.contains("<footer>en</footer>"));
+
+        let second = render(&cache, "en", true).await;
+        assert_eq!(RENDERS.with(Cell::get), 1);
+        assert_eq!(first, second);
+
+        // Different keys and non-hydratable renders are cached separately.
+        assert_eq!(
+            render(&cache, "fr", true).await,
+            first.replace(">en<", ">fr<")
+        );
+        assert_eq!(render(&cache, "en", false).await, "<footer>en</footer>");
+        assert_eq!(RENDERS.with(Cell::get), 3);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_cached_markup_depends_on_tag_and_position() {
+        #[derive(Properties, PartialEq)]
+        struct TextProps {
+            text: AttrValue,
+        }
+
+        #[component]
+        fn Text(props: &TextProps) -> Html {
+            let id = use_id();
+
+            html! { <span {id}>{props.text.clone()}</span> }
+        }
+
+        impl Cacheable for Text {
+            fn cache_key(props: &TextProps) -> Option<String> {
+                Some(props.text.to_string())
+            }
+        }
+
+        #[component]
+        fn Page() -> Html {
+            html! {
+                <style><Cached<Text> text="a < b" /></style>
+                <Cached<Text> text="a < b" />
+                <div><Cached<Text> text="a < b" /></div>
+            }
+        }
+
+        let render = |cache: Option<Arc<LruRenderCache>>| {
+            let mut renderer = LocalServerRenderer::<Page>::new().hydratable(false);
+            if let Some(cache) = cache {
+                renderer = renderer.render_cache(cache);
+            }
+
+            renderer.render()
+        };
+
+        let cache = Arc::new(LruRenderCache::default());
+        let expected = render(None).await;
+        assert_eq!(render(Some(cache.clone())).await, expected);
+        assert_eq!(render(Some(cache.clone())).await, expected);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_lru_render_cache() {
+        let cache = LruRenderCache::new(2);
+
+        cache.insert("a".into(), "1".into());
+        cache.insert("b".into(), "2".into());
+        assert_eq!(cache.get("a").as_deref(), Some("1"));
+
+        // `b` is the least recently used entry.
+        cache.insert("c".into(), "3".into());
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a").as_deref(), Some("1"));
+        assert_eq!(cache.get("c").as_deref(), Some("3"));
+
+        cache.insert("a".into(), "4".into());
+        assert_eq!(cache.get("a").as_deref(), Some("4"));
+        assert_eq!(cache.len(), 2);
+    }
+}
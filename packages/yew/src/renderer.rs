@@ -42,6 +42,8 @@ where
 {
     root: Element,
     props: COMP::Properties,
+    #[cfg(feature = "hydration")]
+    state_codec: crate::functional::StateCodec,
 }
 
 impl<COMP> Default for Renderer<COMP>
@@ -85,7 +87,12 @@ where
 
     /// Creates a [Renderer] that renders into a custom root with custom properties.
     pub fn with_root_and_props(root: Element, props: COMP::Properties) -> Self {
-        Self { root, props }
+        Self {
+            root,
+            props,
+            #[cfg(feature = "hydration")]
+            state_codec: Default::default(),
+        }
     }
 
     /// Renders the application.
@@ -98,15 +105,29 @@ where
 #[cfg(feature = "hydration")]
 mod feat_hydration {
     use super::*;
+    use crate::functional::StateCodec;
 
     impl<COMP> Renderer<COMP>
     where
         COMP: BaseComponent + 'static,
     {
+        /// Sets the codec the states of
+        /// [`use_prepared_state`](crate::functional::use_prepared_state) and
+        /// [`use_transitive_state`](crate::functional::use_transitive_state) are decoded with.
+        ///
+        /// Defaults to [`StateCodec::Bincode`]. It has to match the codec of the server
+        /// renderer. States that cannot be decoded are logged to the console and the hooks
+        /// behave as if no state had been prepared.
+        pub fn state_codec(mut self, codec: StateCodec) -> Self {
+            self.state_codec = codec;
+
+            self
+        }
+
         /// Hydrates the application.
         pub fn hydrate(self) -> AppHandle<COMP> {
             set_default_panic_hook();
-            AppHandle::<COMP>::hydrate_with_props(self.root, Rc::new(self.props))
+            AppHandle::<COMP>::hydrate_with_props(self.root, Rc::new(self.props), self.state_codec)
         }
     }
 }
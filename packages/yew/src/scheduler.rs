@@ -1,8 +1,9 @@
 //! This module contains a scheduler.
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::BTreeMap;
 use std::rc::Rc;
+use std::time::Duration;
 #[cfg(any(test, feature = "test"))]
 mod flush_wakers {
     use std::cell::RefCell;
@@ -119,6 +120,9 @@ struct Scheduler {
 
     rendered_first: TopologicalQueue,
     rendered: TopologicalQueue,
+
+    // Low priority queue, run once all other work is done
+    deferred: FifoQueue,
 }
 
 impl Scheduler {
@@ -134,6 +138,7 @@ impl Scheduler {
             render_priority: TopologicalQueue::new(),
             rendered_first: TopologicalQueue::new(),
             rendered: TopologicalQueue::new(),
+            deferred: FifoQueue::new(),
         }
     }
 }
@@ -152,6 +157,36 @@ fn with<R>(f: impl FnOnce(&mut Scheduler) -> R) -> R {
     SCHEDULER.with(|s| f(&mut s.borrow_mut()))
 }
 
+thread_local! {
+    static FRAME_BUDGET: Cell<Option<Duration>> = const { Cell::new(None) };
+}
+
+/// Enables time-sliced rendering with the given frame budget, or disables it with [`None`].
+///
+/// By default, the scheduler yields to the browser about every 16ms, but only at points where no
+/// component is waiting to be created, rendered or destroyed. A large update, such as mounting a
+/// long list, therefore blocks the browser until it has been rendered completely.
+///
+/// With time-sliced rendering, the scheduler also yields whenever the budget has run out between
+/// the renders of two components, and resumes rendering the remaining components in the same order
+/// afterwards. It still never yields while components are waiting to be created or destroyed.
+/// Event handlers that run while the scheduler has yielded may observe a partially rendered
+/// document, where some components have not been rendered yet.
+///
+/// This only has an effect in the browser, where rendering is asynchronous.
+pub fn set_frame_budget(budget: Option<Duration>) {
+    FRAME_BUDGET.with(|m| m.set(budget));
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(target_os = "wasi"),
+    not(feature = "not_browser_env")
+))]
+fn frame_budget() -> Option<Duration> {
+    FRAME_BUDGET.with(|m| m.get())
+}
+
 /// Push a generic [Runnable] to be executed
 pub fn push(runnable: Box<dyn Runnable>) {
     with(|s| s.main.push(runnable));
@@ -160,6 +195,15 @@ pub fn push(runnable: Box<dyn Runnable>) {
     start();
 }
 
+/// Push a low priority [Runnable] to be executed once all other pending work is done
+///
+/// In the browser, the scheduler yields before running low priority work, so the results of more
+/// urgent work are painted first.
+pub(crate) fn push_deferred(runnable: Box<dyn Runnable>) {
+    with(|s| s.deferred.push(runnable));
+    start();
+}
+
 #[cfg(any(feature = "ssr", feature = "csr"))]
 mod feat_csr_ssr {
     use super::*;
@@ -250,6 +294,9 @@ pub(crate) fn start_now() {
         let mut queue = vec![];
         loop {
             with(|s| s.fill_queue(&mut queue));
+            if queue.is_empty() {
+                with(|s| s.deferred.drain_into(&mut queue));
+            }
             if queue.is_empty() {
                 break;
             }
@@ -257,6 +304,8 @@ pub(crate) fn start_now() {
                 r.task.run();
             }
         }
+        #[cfg(feature = "csr")]
+        crate::suspense::Transition::clear_marks();
     }
 
     thread_local! {
@@ -311,29 +360,26 @@ mod arch {
         fn set_timeout(handler: &js_sys::Function, timeout: i32) -> i32;
     }
 
-    fn run_scheduler(mut queue: Vec<super::QueueEntry>) {
-        let deadline = js_sys::Date::now() + YIELD_DEADLINE_MS;
+    fn yield_to_browser(queue: Vec<super::QueueEntry>, run_deferred: bool) {
+        let cb = Closure::once_into_js(move || run_scheduler(queue, run_deferred));
+        set_timeout(cb.unchecked_ref(), 0);
+    }
 
-        loop {
-            super::with(|s| s.fill_queue(&mut queue));
-            if queue.is_empty() {
-                break;
-            }
-            for r in queue.drain(..) {
-                r.task.run();
-            }
-            if js_sys::Date::now() >= deadline {
-                // Only yield when no DOM-mutating work is pending, so event
-                // handlers that fire during the yield see a consistent DOM.
-                let can_yield = super::with(|s| s.can_yield());
-                if can_yield {
-                    let cb = Closure::once_into_js(move || run_scheduler(queue));
-                    set_timeout(cb.unchecked_ref(), 0);
-                    return;
-                }
-            }
+    fn run_scheduler(mut queue: Vec<super::QueueEntry>, run_deferred: bool) {
+        let budget = super::frame_budget();
+        let deadline =
+            js_sys::Date::now() + budget.map_or(YIELD_DEADLINE_MS, |m| m.as_secs_f64() * 1000.0);
+
+        let slice = super::run_slice(&mut queue, run_deferred, budget.is_some(), || {
+            js_sys::Date::now() >= deadline
+        });
+        if let super::Slice::Yield { run_deferred } = slice {
+            yield_to_browser(queue, run_deferred);
+            return;
         }
 
+        #[cfg(feature = "csr")]
+        crate::suspense::Transition::clear_marks();
         set_scheduled(false);
         #[cfg(any(test, feature = "test"))]
         super::flush_wakers::wake_all();
@@ -349,7 +395,7 @@ mod arch {
         }
         set_scheduled(true);
         spawn_local(async {
-            run_scheduler(vec![]);
+            run_scheduler(vec![], false);
         });
     }
 }
@@ -375,8 +421,9 @@ pub(crate) use arch::*;
 /// Flush all pending scheduler work, ensuring all rendering and lifecycle callbacks complete.
 ///
 /// On browser WebAssembly targets, the scheduler defers its work to the microtask queue.
-/// This function registers a waker that is notified when `start_now()` finishes draining all
+/// This function registers a waker that is notified when the scheduler finishes draining all
 /// queues, providing proper event-driven render-complete notification without arbitrary sleeps.
+/// A scheduler that is already running is awaited, including the times it yields to the browser.
 ///
 /// On non-browser targets, the scheduler runs synchronously so this simply drains pending work.
 ///
@@ -390,7 +437,11 @@ pub(crate) use arch::*;
 ))]
 pub async fn flush() {
     std::future::poll_fn(|cx| {
-        start_now();
+        // A running scheduler is left to finish on its own, so it yields to the browser as it
+        // would outside of tests.
+        if !arch::is_scheduled() {
+            start_now();
+        }
 
         if arch::is_scheduled() {
             flush_wakers::register(cx.waker().clone());
@@ -417,20 +468,84 @@ pub async fn flush() {
     start_now();
 }
 
-impl Scheduler {
-    /// Returns true when no DOM-mutating work is pending, meaning it's safe to
-    /// yield to the browser without leaving the DOM in an inconsistent state.
-    #[cfg(all(
+/// How a slice of the browser scheduler loop has ended.
+#[cfg(any(
+    test,
+    all(
         target_arch = "wasm32",
         not(target_os = "wasi"),
         not(feature = "not_browser_env")
+    )
+))]
+#[derive(Debug, PartialEq)]
+enum Slice {
+    /// All pending work has been run.
+    Done,
+    /// The scheduler yields to the browser and continues afterwards.
+    Yield { run_deferred: bool },
+}
+
+/// Runs pending work until it is done, or until the scheduler yields to the browser once
+/// `out_of_time` returns true.
+#[cfg(any(
+    test,
+    all(
+        target_arch = "wasm32",
+        not(target_os = "wasi"),
+        not(feature = "not_browser_env")
+    )
+))]
+fn run_slice(
+    queue: &mut Vec<QueueEntry>,
+    mut run_deferred: bool,
+    time_sliced: bool,
+    mut out_of_time: impl FnMut() -> bool,
+) -> Slice {
+    loop {
+        with(|s| s.fill_queue(queue));
+        if queue.is_empty() {
+            if with(|s| s.deferred.inner.is_empty()) {
+                return Slice::Done;
+            }
+            // Low priority work only starts after the browser had a chance to paint.
+            if !run_deferred {
+                return Slice::Yield { run_deferred: true };
+            }
+            run_deferred = false;
+            with(|s| s.deferred.drain_into(queue));
+        }
+        for r in queue.drain(..) {
+            r.task.run();
+        }
+        // Only yield when no DOM-mutating work is pending, so event
+        // handlers that fire during the yield see a consistent DOM.
+        if out_of_time() && with(|s| s.can_yield(time_sliced)) {
+            return Slice::Yield {
+                run_deferred: false,
+            };
+        }
+    }
+}
+
+impl Scheduler {
+    /// Returns true when no DOM-mutating work is pending, meaning it's safe to
+    /// yield to the browser without leaving the DOM in an inconsistent state.
+    ///
+    /// With time-sliced rendering, pending renders do not prevent yielding, as they leave
+    /// the DOM of the components that have not been rendered yet as it is.
+    #[cfg(any(
+        test,
+        all(
+            target_arch = "wasm32",
+            not(target_os = "wasi"),
+            not(feature = "not_browser_env")
+        )
     ))]
-    fn can_yield(&self) -> bool {
+    fn can_yield(&self, time_sliced: bool) -> bool {
         self.destroy.inner.is_empty()
             && self.create.inner.is_empty()
-            && self.render_first.inner.is_empty()
-            && self.render.inner.is_empty()
             && self.render_priority.inner.is_empty()
+            && (time_sliced || (self.render_first.inner.is_empty() && self.render.inner.is_empty()))
     }
 
     /// Fill vector with tasks to be executed according to Runnable type execution priority
@@ -527,4 +642,92 @@ mod tests {
         push(Box::new(Test));
         FLAG.with(|v| assert!(v.get()));
     }
+
+    #[test]
+    fn deferred_runnables_run_last() {
+        thread_local! {
+            static ORDER: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Log(&'static str);
+        impl Runnable for Log {
+            fn run(self: Box<Self>) {
+                ORDER.with(|m| m.borrow_mut().push(self.0));
+            }
+        }
+
+        struct Spawn;
+        impl Runnable for Spawn {
+            fn run(self: Box<Self>) {
+                push_deferred(Box::new(Log("deferred")));
+                push(Box::new(Log("main")));
+            }
+        }
+
+        push(Box::new(Spawn));
+        ORDER.with(|m| assert_eq!(*m.borrow(), ["main", "deferred"]));
+    }
+
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    #[test]
+    fn render_order_survives_yield() {
+        thread_local! {
+            static ORDER: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Render(usize);
+        impl Runnable for Render {
+            fn run(self: Box<Self>) {
+                ORDER.with(|m| m.borrow_mut().push(self.0));
+            }
+        }
+
+        with(|s| {
+            for id in [5, 3, 9] {
+                s.render.push(id, Box::new(Render(id)));
+            }
+        });
+
+        // The frame budget runs out after a single component has been rendered.
+        let mut queue = vec![];
+        assert_eq!(
+            run_slice(&mut queue, false, true, || true),
+            Slice::Yield {
+                run_deferred: false
+            }
+        );
+        ORDER.with(|m| assert_eq!(*m.borrow(), [3]));
+
+        // A parent and a child are scheduled while the scheduler has yielded.
+        with(|s| {
+            s.render.push(1, Box::new(Render(1)));
+            s.render.push(7, Box::new(Render(7)));
+        });
+
+        assert_eq!(run_slice(&mut queue, false, true, || false), Slice::Done);
+        ORDER.with(|m| assert_eq!(*m.borrow(), [3, 1, 5, 7, 9]));
+    }
+
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    #[test]
+    fn time_sliced_rendering_does_not_yield_before_creation() {
+        struct Noop;
+        impl Runnable for Noop {
+            fn run(self: Box<Self>) {}
+        }
+
+        let mut s = Scheduler::new();
+        assert!(s.can_yield(false));
+
+        s.create.push(Box::new(Noop));
+        s.render_first.push(1, Box::new(Noop));
+        assert!(!s.can_yield(true));
+
+        s.create.inner.clear();
+        assert!(s.can_yield(true));
+        assert!(!s.can_yield(false));
+
+        s.destroy.push(Box::new(Noop));
+        assert!(!s.can_yield(true));
+    }
 }
@@ -1,15 +1,96 @@
-use std::fmt;
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::fmt::{self, Write};
+use std::io;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{self, Poll};
+use std::time::Duration;
 
-use futures::pin_mut;
+use futures::channel::mpsc::UnboundedReceiver;
+use futures::future::{self, AbortHandle, Abortable, Either};
+use futures::io::{AsyncWrite, AsyncWriteExt};
 use futures::stream::{Stream, StreamExt};
+use futures::{FutureExt, pin_mut};
 use tracing::Instrument;
 
+use crate::feat_ssr::RenderState;
+use crate::functional::{
+    ResponseParts, SendServerContexts, ServerContexts, StateCodec, into_local_contexts,
+};
+use crate::head::HeadTags;
 use crate::html::{BaseComponent, Scope};
-use crate::platform::fmt::BufStream;
+use crate::platform::fmt::{self as platform_fmt, BufStream};
 use crate::platform::{LocalHandle, Runtime};
+use crate::render_cache::RenderCache;
+use crate::suspense::{Deadline, DeferredRender};
 
 #[cfg(feature = "ssr")]
 pub(crate) mod feat_ssr {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::iter;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use crate::functional::{ResponseParts, ServerContexts, StateCodec};
+    use crate::head::HeadTags;
+    use crate::html::AnyScope;
+    use crate::render_cache::RenderCache;
+    use crate::suspense::{Deadline, DeferredRender};
+
+    thread_local! {
+        static RENDERS: RefCell<HashMap<usize, Rc<RenderState>>> = RefCell::default();
+    }
+
+    /// The state of a render, shared with the components it renders.
+    ///
+    /// It is keyed by the id of the root scope of the render, so it can be found from any
+    /// component without threading it through `render_into_stream`.
+    #[derive(Default)]
+    pub(crate) struct RenderState {
+        pub deferred: Option<Rc<DeferredRender>>,
+        pub deadline: Option<Deadline>,
+        pub head: Option<HeadTags>,
+        pub state_codec: StateCodec,
+        pub render_cache: Option<Arc<dyn RenderCache>>,
+        pub contexts: ServerContexts,
+        pub response: Option<Rc<RefCell<ResponseParts>>>,
+    }
+
+    impl RenderState {
+        /// Registers the state of the render with the root scope `root_id`.
+        ///
+        /// The state is removed when the returned guard is dropped, which also happens when the
+        /// render is dropped before it has finished.
+        pub fn register(root_id: usize, state: Self) -> Registration {
+            RENDERS.with(|m| m.borrow_mut().insert(root_id, Rc::new(state)));
+
+            Registration { root_id }
+        }
+
+        /// Finds the state of the render the component at `scope` belongs to.
+        pub fn find(scope: &AnyScope) -> Option<Rc<Self>> {
+            if RENDERS.with(|m| m.borrow().is_empty()) {
+                return None;
+            }
+
+            let root = iter::successors(Some(scope), |m| m.get_parent()).last()?;
+            RENDERS.with(|m| m.borrow().get(&root.id).cloned())
+        }
+    }
+
+    pub(crate) struct Registration {
+        root_id: usize,
+    }
+
+    impl Drop for Registration {
+        fn drop(&mut self) {
+            RENDERS.with(|m| m.borrow_mut().remove(&self.root_id));
+        }
+    }
+
     /// Passed top-down as context for `render_into_stream` functions to know the current innermost
     /// `VTag` kind to apply appropriate text escaping.
     /// Right now this is used to make `VText` nodes aware of their environment and correctly
@@ -39,6 +120,101 @@ pub(crate) mod feat_ssr {
     }
 }
 
+/// The default number of bytes written before the writer of `render_to_writer` is flushed.
+const DEFAULT_FLUSH_THRESHOLD: usize = 8 * 1024;
+
+/// Writes the chunks of `s` into `w`.
+///
+/// Each chunk is the buffer a [`BufWriter`](platform_fmt::BufWriter) has been written into since
+/// the previous chunk, and is written as it is, without being copied. The writer is flushed once
+/// `flush_threshold` bytes have been written since the last flush, when the next chunk is not ready
+/// yet and at the end of the stream, unless everything has been flushed already.
+async fn write_stream<S, W>(s: S, w: &mut W, flush_threshold: usize) -> io::Result<()>
+where
+    S: Stream<Item = String>,
+    W: AsyncWrite + Unpin,
+{
+    pin_mut!(s);
+
+    let mut unflushed = 0;
+    loop {
+        let chunk = match s.next().now_or_never() {
+            Some(m) => m,
+            None => {
+                // Sends the part of the document that is ready while the rest is rendered.
+                if unflushed > 0 {
+                    w.flush().await?;
+                    unflushed = 0;
+                }
+
+                s.next().await
+            }
+        };
+        let Some(chunk) = chunk else {
+            break;
+        };
+
+        w.write_all(chunk.as_bytes()).await?;
+        unflushed += chunk.len();
+
+        if unflushed >= flush_threshold {
+            w.flush().await?;
+            unflushed = 0;
+        }
+    }
+
+    if unflushed > 0 {
+        w.flush().await?;
+    }
+
+    Ok(())
+}
+
+/// Adapts a [`tokio::io::AsyncWrite`] to [`futures::io::AsyncWrite`].
+#[cfg(feature = "tokio")]
+struct TokioWriter<'a, W>(&'a mut W);
+
+#[cfg(feature = "tokio")]
+impl<W> AsyncWrite for TokioWriter<'_, W>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    fn poll_write(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<io::Result<usize>> {
+        std::pin::Pin::new(&mut *self.get_mut().0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<io::Result<()>> {
+        std::pin::Pin::new(&mut *self.get_mut().0).poll_flush(cx)
+    }
+
+    fn poll_close(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<io::Result<()>> {
+        std::pin::Pin::new(&mut *self.get_mut().0).poll_shutdown(cx)
+    }
+}
+
+/// The result of [`ServerRenderer::render_response`] and
+/// [`LocalServerRenderer::render_response`].
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerResponse {
+    /// The rendered application.
+    pub body: String,
+    /// The status code set by the components, `200` if none has been set.
+    pub status: u16,
+    /// The headers set by the components, in the order they have been set.
+    pub headers: Vec<(String, String)>,
+}
+
 /// A Yew Server-side Renderer that renders on the current thread.
 ///
 /// # Note
@@ -56,6 +232,13 @@ where
 {
     props: COMP::Properties,
     hydratable: bool,
+    out_of_order: bool,
+    head: Option<HeadTags>,
+    state_codec: StateCodec,
+    flush_threshold: usize,
+    timeout: Option<Duration>,
+    render_cache: Option<Arc<dyn RenderCache>>,
+    contexts: ServerContexts,
 }
 
 impl<COMP> Default for LocalServerRenderer<COMP>
@@ -86,6 +269,13 @@ where
         Self {
             props,
             hydratable: true,
+            out_of_order: false,
+            head: None,
+            state_codec: StateCodec::default(),
+            flush_threshold: DEFAULT_FLUSH_THRESHOLD,
+            timeout: None,
+            render_cache: None,
+            contexts: ServerContexts::new(),
         }
     }
 
@@ -101,6 +291,94 @@ where
         self
     }
 
+    /// Sets whether suspended subtrees are streamed out of order.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// By default, the renderer waits for each [`Suspense`](crate::suspense::Suspense) in
+    /// document order. When this is set to `true`, the fallback of a suspended boundary is written
+    /// right away and the rest of the document continues to render. The content of the boundary
+    /// is appended to the stream once it resolves, together with an inline script that replaces
+    /// the fallback with it.
+    ///
+    /// Hydration understands both the swapped and the unswapped markup.
+    pub fn out_of_order(mut self, val: bool) -> Self {
+        self.out_of_order = val;
+
+        self
+    }
+
+    /// Sets the collection the tags of [`Head`](crate::head::Head) components are rendered into.
+    ///
+    /// The tags are not part of the rendered body. They are complete once the render has
+    /// finished, so they can be written into the head of the document.
+    pub fn head(mut self, head: HeadTags) -> Self {
+        self.head = Some(head);
+
+        self
+    }
+
+    /// Sets the codec the states of
+    /// [`use_prepared_state`](crate::functional::use_prepared_state) and
+    /// [`use_transitive_state`](crate::functional::use_transitive_state) are encoded with.
+    ///
+    /// Defaults to [`StateCodec::Bincode`]. The application has to be hydrated with the same
+    /// codec.
+    pub fn state_codec(mut self, codec: StateCodec) -> Self {
+        self.state_codec = codec;
+
+        self
+    }
+
+    /// Sets how long the renderer waits for suspended components.
+    ///
+    /// Defaults to `None`, which waits until every component has resumed. Once the timeout has
+    /// elapsed, each [`Suspense`](crate::suspense::Suspense) that is still suspended is written
+    /// with its fallback and its suspended components are destroyed. When the application is
+    /// hydrated, the children of these boundaries are rendered on the client.
+    pub fn timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.timeout = timeout.into();
+
+        self
+    }
+
+    /// Sets the cache the markup of [`Cached`](crate::render_cache::Cached) components is
+    /// replayed from.
+    ///
+    /// Defaults to `None`, which renders these components like any other component. The cache
+    /// is bypassed when suspended subtrees are streamed [out of order](Self::out_of_order).
+    pub fn render_cache(mut self, cache: Arc<dyn RenderCache>) -> Self {
+        self.render_cache = Some(cache);
+
+        self
+    }
+
+    /// Passes `value` to the components of the render.
+    ///
+    /// The value can be read by any component with
+    /// [`use_server_context`](crate::functional::use_server_context), which makes it suitable
+    /// for the data of the request being rendered, such as its URL, headers or cookies. A value
+    /// of the same type passed before is replaced.
+    pub fn with_context<T>(mut self, value: T) -> Self
+    where
+        T: 'static,
+    {
+        self.contexts.insert(TypeId::of::<T>(), Box::new(value));
+
+        self
+    }
+
+    /// Sets the number of bytes [`render_to_writer`](Self::render_to_writer) writes before it
+    /// flushes the writer.
+    ///
+    /// Defaults to 8 KiB. The writer is also flushed whenever the render waits for a suspended
+    /// component, so the part of the document that is ready reaches the client.
+    pub fn flush_threshold(mut self, bytes: usize) -> Self {
+        self.flush_threshold = bytes;
+
+        self
+    }
+
     /// Renders Yew Application.
     pub async fn render(self) -> String {
         let s = self.render_stream();
@@ -109,6 +387,26 @@ where
         s.collect().await
     }
 
+    /// Renders Yew Application with the status and the headers of its response.
+    ///
+    /// The status and the headers are set by the components of the application with
+    /// [`use_server_response`](crate::functional::use_server_response). They are complete once
+    /// the whole body has been rendered.
+    pub async fn render_response(self) -> ServerResponse {
+        let response = Rc::new(RefCell::new(ResponseParts::default()));
+        let s = self.render_stream_inner(Some(response.clone()));
+        futures::pin_mut!(s);
+
+        let body = s.collect().await;
+        let ResponseParts { status, headers } = response.take();
+
+        ServerResponse {
+            body,
+            status: status.unwrap_or(200),
+            headers,
+        }
+    }
+
     /// Renders Yew Application to a String.
     pub async fn render_to_string(self, w: &mut String) {
         let s = self.render_stream();
@@ -119,22 +417,97 @@ where
         }
     }
 
-    fn render_stream_inner(self) -> impl Stream<Item = String> {
+    /// Renders Yew Application into a [`futures::io::AsyncWrite`].
+    ///
+    /// The rendered chunks are written as they are produced, so the output can be piped into
+    /// the body of a response without collecting it first. The writer is flushed according to
+    /// [`flush_threshold`](Self::flush_threshold) and once the render has finished, but it is not
+    /// closed.
+    pub async fn render_to_writer<W>(self, w: &mut W) -> io::Result<()>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let flush_threshold = self.flush_threshold;
+        write_stream(self.render_stream_inner(None), w, flush_threshold).await
+    }
+
+    /// Renders Yew Application into a [`tokio::io::AsyncWrite`].
+    ///
+    /// See [`render_to_writer`](Self::render_to_writer).
+    #[cfg(feature = "tokio")]
+    pub async fn render_to_tokio_writer<W>(self, w: &mut W) -> io::Result<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        self.render_to_writer(&mut TokioWriter(w)).await
+    }
+
+    fn render_stream_inner(
+        self,
+        response: Option<Rc<RefCell<ResponseParts>>>,
+    ) -> impl Stream<Item = String> {
         let scope = Scope::<COMP>::new(None);
 
         let outer_span = tracing::Span::current();
         BufStream::new(move |mut w| async move {
             let render_span = tracing::debug_span!("render_stream_item");
             render_span.follows_from(outer_span);
-            scope
-                .render_into_stream(
-                    &mut w,
-                    self.props.into(),
-                    self.hydratable,
-                    Default::default(),
-                )
-                .instrument(render_span)
-                .await;
+
+            let state = RenderState {
+                deferred: self.out_of_order.then(|| Rc::new(DeferredRender::new())),
+                deadline: self.timeout.map(Deadline::new),
+                head: self.head,
+                state_codec: self.state_codec,
+                render_cache: self.render_cache,
+                contexts: self.contexts,
+                response,
+            };
+            let deferred = state.deferred.clone();
+            let deadline = state.deadline.clone();
+            let _registration = RenderState::register(scope.id, state);
+
+            let render_shell = async {
+                scope
+                    .render_into_stream(
+                        &mut w,
+                        self.props.into(),
+                        self.hydratable,
+                        Default::default(),
+                    )
+                    .instrument(render_span)
+                    .await;
+
+                w
+            };
+
+            match deferred {
+                None => {
+                    render_shell.await;
+                }
+                Some(deferred) => {
+                    // Deferred boundaries are rendered alongside the shell, but can only be
+                    // written after it.
+                    let (mut deferred_w, deferred_r) = platform_fmt::buffer();
+                    let render_deferred = {
+                        let deferred = &deferred;
+                        async move {
+                            deferred.render_deferred(&mut deferred_w, deadline).await;
+                        }
+                    };
+                    let write_deferred = async {
+                        let mut w = render_shell.await;
+                        deferred.finish_shell();
+
+                        pin_mut!(deferred_r);
+                        while let Some(m) = deferred_r.next().await {
+                            let _ = w.write_str(&m);
+                        }
+                    };
+
+                    futures::join!(render_deferred, write_deferred);
+                    deferred.finish();
+                }
+            }
         })
     }
 
@@ -150,7 +523,7 @@ where
     )]
     #[inline(always)]
     pub fn render_stream(self) -> impl Stream<Item = String> {
-        self.render_stream_inner()
+        self.render_stream_inner(None)
     }
 }
 
@@ -167,6 +540,13 @@ where
 {
     create_props: Box<dyn Send + FnOnce() -> COMP::Properties>,
     hydratable: bool,
+    out_of_order: bool,
+    head: Option<HeadTags>,
+    state_codec: StateCodec,
+    flush_threshold: usize,
+    timeout: Option<Duration>,
+    render_cache: Option<Arc<dyn RenderCache>>,
+    contexts: SendServerContexts,
     rt: Option<Runtime>,
 }
 
@@ -215,6 +595,13 @@ where
         Self {
             create_props: Box::new(create_props),
             hydratable: true,
+            out_of_order: false,
+            head: None,
+            state_codec: StateCodec::default(),
+            flush_threshold: DEFAULT_FLUSH_THRESHOLD,
+            timeout: None,
+            render_cache: None,
+            contexts: SendServerContexts::new(),
             rt: None,
         }
     }
@@ -238,21 +625,144 @@ where
         self
     }
 
+    /// Sets whether suspended subtrees are streamed out of order.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// By default, the renderer waits for each [`Suspense`](crate::suspense::Suspense) in
+    /// document order. When this is set to `true`, the fallback of a suspended boundary is written
+    /// right away and the rest of the document continues to render. The content of the boundary
+    /// is appended to the stream once it resolves, together with an inline script that replaces
+    /// the fallback with it.
+    ///
+    /// Hydration understands both the swapped and the unswapped markup.
+    pub fn out_of_order(mut self, val: bool) -> Self {
+        self.out_of_order = val;
+
+        self
+    }
+
+    /// Sets the collection the tags of [`Head`](crate::head::Head) components are rendered into.
+    ///
+    /// The tags are not part of the rendered body. They are complete once the render has
+    /// finished, so they can be written into the head of the document.
+    pub fn head(mut self, head: HeadTags) -> Self {
+        self.head = Some(head);
+
+        self
+    }
+
+    /// Sets the codec the states of
+    /// [`use_prepared_state`](crate::functional::use_prepared_state) and
+    /// [`use_transitive_state`](crate::functional::use_transitive_state) are encoded with.
+    ///
+    /// Defaults to [`StateCodec::Bincode`]. The application has to be hydrated with the same
+    /// codec.
+    pub fn state_codec(mut self, codec: StateCodec) -> Self {
+        self.state_codec = codec;
+
+        self
+    }
+
+    /// Sets how long the renderer waits for suspended components.
+    ///
+    /// Defaults to `None`, which waits until every component has resumed. Once the timeout has
+    /// elapsed, each [`Suspense`](crate::suspense::Suspense) that is still suspended is written
+    /// with its fallback and its suspended components are destroyed. When the application is
+    /// hydrated, the children of these boundaries are rendered on the client.
+    pub fn timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.timeout = timeout.into();
+
+        self
+    }
+
+    /// Sets the cache the markup of [`Cached`](crate::render_cache::Cached) components is
+    /// replayed from.
+    ///
+    /// Defaults to `None`, which renders these components like any other component. The cache
+    /// is bypassed when suspended subtrees are streamed [out of order](Self::out_of_order).
+    pub fn render_cache(mut self, cache: Arc<dyn RenderCache>) -> Self {
+        self.render_cache = Some(cache);
+
+        self
+    }
+
+    /// Passes `value` to the components of the render.
+    ///
+    /// The value can be read by any component with
+    /// [`use_server_context`](crate::functional::use_server_context), which makes it suitable
+    /// for the data of the request being rendered, such as its URL, headers or cookies. A value
+    /// of the same type passed before is replaced.
+    ///
+    /// The value has to be `Send`, as it is moved to the thread the application is rendered on.
+    /// Values that are not `Send` can be passed to
+    /// [`LocalServerRenderer::with_context`](LocalServerRenderer::with_context).
+    pub fn with_context<T>(mut self, value: T) -> Self
+    where
+        T: Send + 'static,
+    {
+        self.contexts.insert(TypeId::of::<T>(), Box::new(value));
+
+        self
+    }
+
+    /// Sets the number of bytes [`render_to_writer`](Self::render_to_writer) writes before it
+    /// flushes the writer.
+    ///
+    /// Defaults to 8 KiB. The writer is also flushed whenever the render waits for a suspended
+    /// component, so the part of the document that is ready reaches the client.
+    pub fn flush_threshold(mut self, bytes: usize) -> Self {
+        self.flush_threshold = bytes;
+
+        self
+    }
+
     /// Renders Yew Application.
     pub async fn render(self) -> String {
+        self.render_response().await.body
+    }
+
+    /// Renders Yew Application with the status and the headers of its response.
+    ///
+    /// The status and the headers are set by the components of the application with
+    /// [`use_server_response`](crate::functional::use_server_response). They are complete once
+    /// the whole body has been rendered.
+    pub async fn render_response(self) -> ServerResponse {
         let Self {
             create_props,
             hydratable,
+            out_of_order,
+            head,
+            state_codec,
+            flush_threshold: _,
+            timeout,
+            render_cache,
+            contexts,
             rt,
         } = self;
 
-        let (tx, rx) = futures::channel::oneshot::channel();
+        let (mut tx, rx) = futures::channel::oneshot::channel();
         let create_task = move || async move {
             let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
-                .hydratable(hydratable)
-                .render()
-                .await;
+            let render = LocalServerRenderer::<COMP> {
+                head,
+                render_cache,
+                contexts: into_local_contexts(contexts),
+                ..LocalServerRenderer::with_props(props)
+                    .hydratable(hydratable)
+                    .out_of_order(out_of_order)
+                    .state_codec(state_codec)
+                    .timeout(timeout)
+            }
+            .render_response();
+            pin_mut!(render);
+
+            let s = match future::select(render, tx.cancellation()).await {
+                Either::Left((s, _)) => s,
+                // The render is dropped with the components it has created once the result is no
+                // longer awaited.
+                Either::Right(_) => return,
+            };
 
             let _ = tx.send(s);
         };
@@ -271,6 +781,33 @@ where
         }
     }
 
+    /// Renders Yew Application into a [`futures::io::AsyncWrite`].
+    ///
+    /// The rendered chunks are written as they are produced, so the output can be piped into
+    /// the body of a response without collecting it first. The writer is flushed according to
+    /// [`flush_threshold`](Self::flush_threshold) and once the render has finished, but it is not
+    /// closed.
+    pub async fn render_to_writer<W>(self, w: &mut W) -> io::Result<()>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let flush_threshold = self.flush_threshold;
+        // The chunks are moved out of the rendering task as they are, so they are not copied
+        // either.
+        write_stream(self.render_stream(), w, flush_threshold).await
+    }
+
+    /// Renders Yew Application into a [`tokio::io::AsyncWrite`].
+    ///
+    /// See [`render_to_writer`](Self::render_to_writer).
+    #[cfg(feature = "tokio")]
+    pub async fn render_to_tokio_writer<W>(self, w: &mut W) -> io::Result<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        self.render_to_writer(&mut TokioWriter(w)).await
+    }
+
     #[inline]
     fn spawn_rendering_task<F, Fut>(rt: Option<Runtime>, create_task: F)
     where
@@ -294,24 +831,226 @@ where
         let Self {
             create_props,
             hydratable,
+            out_of_order,
+            head,
+            state_codec,
+            flush_threshold: _,
+            timeout,
+            render_cache,
+            contexts,
             rt,
         } = self;
 
         let (tx, rx) = futures::channel::mpsc::unbounded();
+        let (abort, abort_reg) = AbortHandle::new_pair();
         let create_task = move || async move {
             let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
-                .hydratable(hydratable)
-                .render_stream();
-            pin_mut!(s);
-
-            while let Some(m) = s.next().await {
-                let _ = tx.unbounded_send(m);
+            let s = LocalServerRenderer::<COMP> {
+                head,
+                render_cache,
+                contexts: into_local_contexts(contexts),
+                ..LocalServerRenderer::with_props(props)
+                    .hydratable(hydratable)
+                    .out_of_order(out_of_order)
+                    .state_codec(state_codec)
+                    .timeout(timeout)
             }
+            .render_stream();
+            let render = async move {
+                pin_mut!(s);
+
+                while let Some(m) = s.next().await {
+                    let _ = tx.unbounded_send(m);
+                }
+            };
+
+            // The render is dropped with the components it has created once it is aborted.
+            let _ = Abortable::new(render, abort_reg).await;
         };
 
         Self::spawn_rendering_task(rt, create_task);
 
-        rx
+        RenderStream { rx, abort }
+    }
+}
+
+/// The stream returned by [`ServerRenderer::render_stream`].
+///
+/// The render is aborted when the stream is dropped.
+struct RenderStream {
+    rx: UnboundedReceiver<String>,
+    abort: AbortHandle,
+}
+
+impl Stream for RenderStream {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for RenderStream {
+    fn drop(&mut self) {
+        self.abort.abort();
+    }
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+    use std::time::Duration;
+
+    use tokio::test;
+
+    use super::*;
+    use crate::platform::time::sleep;
+    use crate::prelude::*;
+    use crate::suspense::use_future;
+
+    /// Records the output written before each flush.
+    #[derive(Default)]
+    struct FlushRecorder {
+        buf: Vec<u8>,
+        flushed: Vec<String>,
+    }
+
+    impl AsyncWrite for FlushRecorder {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.get_mut().buf.extend_from_slice(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            let this = self.get_mut();
+            this.flushed
+                .push(String::from_utf8(this.buf.clone()).unwrap());
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[component]
+    fn Slow() -> HtmlResult {
+        use_future(|| async {
+            sleep(Duration::from_millis(50)).await;
+        })?;
+
+        Ok(html! { <p>{"slow"}</p> })
+    }
+
+    #[component]
+    fn Comp() -> Html {
+        html! {
+            <h1>{"title"}</h1>
+            <Suspense fallback={html! {"loading"}}>
+                <Slow />
+            </Suspense>
+        }
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_render_to_writer() {
+        let mut w = FlushRecorder::default();
+        ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render_to_writer(&mut w)
+            .await
+            .unwrap();
+
+        // The part rendered before the suspension is flushed before the rest is ready.
+        assert_eq!(
+            w.flushed,
+            vec!["<h1>title</h1>", "<h1>title</h1><p>slow</p>"]
+        );
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_flush_threshold() {
+        let chunks = || futures::stream::iter(["ab", "cd", "ef"].map(String::from));
+
+        let mut w = FlushRecorder::default();
+        write_stream(chunks(), &mut w, 4).await.unwrap();
+        assert_eq!(w.flushed, vec!["abcd", "abcdef"]);
+
+        let mut w = FlushRecorder::default();
+        write_stream(chunks(), &mut w, 0).await.unwrap();
+        assert_eq!(w.flushed, vec!["ab", "abcd", "abcdef"]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_render_to_tokio_writer() {
+        let mut w = Vec::new();
+        ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render_to_tokio_writer(&mut w)
+            .await
+            .unwrap();
+
+        assert_eq!(String::from_utf8(w).unwrap(), "<h1>title</h1><p>slow</p>");
+    }
+
+    #[cfg_attr(not(target_os = "wasi"), test)]
+    #[cfg_attr(target_os = "wasi", test(flavor = "current_thread"))]
+    async fn test_dropping_stream_cancels_render() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static COMPONENT_DROPPED: AtomicBool = AtomicBool::new(false);
+        static FUTURE_DROPPED: AtomicBool = AtomicBool::new(false);
+        static FUTURE_FINISHED: AtomicBool = AtomicBool::new(false);
+
+        struct SetOnDrop(&'static AtomicBool);
+
+        impl Drop for SetOnDrop {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        #[component]
+        fn Endless() -> HtmlResult {
+            use_state(|| SetOnDrop(&COMPONENT_DROPPED));
+            use_future(|| async {
+                let _guard = SetOnDrop(&FUTURE_DROPPED);
+                sleep(Duration::from_secs(60)).await;
+                FUTURE_FINISHED.store(true, Ordering::SeqCst);
+            })?;
+
+            Ok(Html::default())
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! {"loading"}}>
+                    <Endless />
+                </Suspense>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new().render_stream();
+        sleep(Duration::from_millis(50)).await;
+        assert!(!COMPONENT_DROPPED.load(Ordering::SeqCst));
+        assert!(!FUTURE_DROPPED.load(Ordering::SeqCst));
+
+        drop(s);
+        sleep(Duration::from_millis(50)).await;
+        assert!(COMPONENT_DROPPED.load(Ordering::SeqCst));
+        assert!(FUTURE_DROPPED.load(Ordering::SeqCst));
+        assert!(!FUTURE_FINISHED.load(Ordering::SeqCst));
     }
 }
@@ -16,9 +16,10 @@ pub struct SuspenseProps {
 mod feat_csr_ssr {
     use super::*;
     use crate::html::{Component, Context, Html, Scope};
-    use crate::suspense::Suspension;
     #[cfg(feature = "hydration")]
     use crate::suspense::SuspensionHandle;
+    use crate::suspense::list::{BaseSuspenseList, BaseSuspenseListMsg, ListReveal};
+    use crate::suspense::{Suspension, Transition};
     use crate::virtual_dom::{VNode, VSuspense};
     use crate::{component, html};
 
@@ -31,22 +32,28 @@ mod feat_csr_ssr {
 
     #[derive(Debug)]
     pub(crate) enum BaseSuspenseMsg {
-        Suspend(Suspension),
+        Suspend(Suspension, Option<Transition>),
         Resume(Suspension),
+        Reveal(ListReveal),
     }
 
     #[derive(Debug)]
     pub(crate) struct BaseSuspense {
         suspensions: Vec<Suspension>,
+        /// Suspensions of transitions, which keep the children visible.
+        held: Vec<(Suspension, Transition)>,
         #[cfg(feature = "hydration")]
         hydration_handle: Option<SuspensionHandle>,
+        /// The list coordinating this boundary, if any.
+        list: Option<Scope<BaseSuspenseList>>,
+        reveal: ListReveal,
     }
 
     impl Component for BaseSuspense {
         type Message = BaseSuspenseMsg;
         type Properties = BaseSuspenseProps;
 
-        fn create(_ctx: &Context<Self>) -> Self {
+        fn create(ctx: &Context<Self>) -> Self {
             #[cfg(not(feature = "hydration"))]
             let suspensions = Vec::new();
 
@@ -56,9 +63,9 @@ mod feat_csr_ssr {
                 use crate::callback::Callback;
                 use crate::html::RenderMode;
 
-                match _ctx.creation_mode() {
+                match ctx.creation_mode() {
                     RenderMode::Hydration => {
-                        let link = _ctx.link().clone();
+                        let link = ctx.link().clone();
                         let (s, handle) = Suspension::new();
                         s.listen(Callback::from(move |s| {
                             link.send_message(BaseSuspenseMsg::Resume(s));
@@ -69,16 +76,32 @@ mod feat_csr_ssr {
                 }
             };
 
+            let list = ctx
+                .props()
+                .fallback
+                .as_ref()
+                .and_then(|_| BaseSuspenseList::find(ctx.link().get_parent()));
+            if let Some(ref m) = list {
+                m.send_message(BaseSuspenseListMsg::Register(
+                    ctx.link().clone(),
+                    suspensions.is_empty(),
+                ));
+            }
+
             Self {
                 suspensions,
+                held: Vec::new(),
                 #[cfg(feature = "hydration")]
                 hydration_handle,
+                list,
+                reveal: ListReveal::default(),
             }
         }
 
         fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-            match msg {
-                Self::Message::Suspend(m) => {
+            let resolved = self.suspensions.is_empty();
+            let should_render = match msg {
+                Self::Message::Suspend(m, transition) => {
                     assert!(
                         ctx.props().fallback.is_some(),
                         "You cannot suspend from a component rendered as a fallback."
@@ -89,21 +112,57 @@ mod feat_csr_ssr {
                     }
 
                     // If a suspension already exists, ignore it.
-                    if self.suspensions.iter().any(|n| n == &m) {
+                    if self
+                        .suspensions
+                        .iter()
+                        .chain(self.held.iter().map(|(n, _)| n))
+                        .any(|n| n == &m)
+                    {
                         return false;
                     }
 
-                    self.suspensions.push(m);
+                    match transition {
+                        // The children are only kept visible if they are not already replaced
+                        // by the fallback.
+                        Some(transition) if self.suspensions.is_empty() => {
+                            self.held.push((m, transition));
 
-                    true
+                            false
+                        }
+                        _ => {
+                            self.suspensions.push(m);
+
+                            true
+                        }
+                    }
                 }
                 Self::Message::Resume(ref m) => {
+                    // Settles the transition of a held suspension once it is dropped.
+                    self.held.retain(|(n, _)| m != n);
+
                     let suspensions_len = self.suspensions.len();
                     self.suspensions.retain(|n| m != n);
 
                     suspensions_len != self.suspensions.len()
                 }
+                Self::Message::Reveal(m) => {
+                    let changed = self.reveal != m;
+                    self.reveal = m;
+
+                    changed
+                }
+            };
+
+            if let Some(ref m) = self.list {
+                if resolved != self.suspensions.is_empty() {
+                    m.send_message(BaseSuspenseListMsg::Update(
+                        ctx.link().id,
+                        self.suspensions.is_empty(),
+                    ));
+                }
             }
+
+            should_render
         }
 
         fn view(&self, ctx: &Context<Self>) -> Html {
@@ -114,10 +173,15 @@ mod feat_csr_ssr {
 
             match fallback {
                 Some(fallback) => {
+                    // A list may hold back the content of a resolved boundary.
+                    let fallback = match self.reveal {
+                        ListReveal::Hidden => Html::default(),
+                        _ => fallback,
+                    };
                     let vsuspense = VSuspense::new(
                         children,
                         fallback,
-                        !self.suspensions.is_empty(),
+                        !self.suspensions.is_empty() || self.reveal != ListReveal::Content,
                         // We don't need to key this as the key will be applied to the component.
                         None,
                     );
@@ -128,6 +192,12 @@ mod feat_csr_ssr {
             }
         }
 
+        fn destroy(&mut self, ctx: &Context<Self>) {
+            if let Some(ref m) = self.list {
+                m.send_message(BaseSuspenseListMsg::Unregister(ctx.link().id));
+            }
+        }
+
         #[cfg(feature = "hydration")]
         fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
             if first_render {
@@ -139,13 +209,17 @@ mod feat_csr_ssr {
     }
 
     impl BaseSuspense {
-        pub(crate) fn suspend(scope: &Scope<Self>, s: Suspension) {
-            scope.send_message(BaseSuspenseMsg::Suspend(s));
+        pub(crate) fn suspend(scope: &Scope<Self>, s: Suspension, transition: Option<Transition>) {
+            scope.send_message(BaseSuspenseMsg::Suspend(s, transition));
         }
 
         pub(crate) fn resume(scope: &Scope<Self>, s: Suspension) {
             scope.send_message(BaseSuspenseMsg::Resume(s));
         }
+
+        pub(crate) fn reveal(scope: &Scope<Self>, reveal: ListReveal) {
+            scope.send_message(BaseSuspenseMsg::Reveal(reveal));
+        }
     }
 
     /// Suspend rendering and show a fallback UI until the underlying task completes.
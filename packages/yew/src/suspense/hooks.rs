@@ -3,9 +3,12 @@ use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
 
+use futures::future::{AbortHandle, abortable};
 use yew::prelude::*;
 use yew::suspense::{Suspension, SuspensionResult};
 
+use crate::functional::{Hook, HookContext};
+
 /// This hook is used to await a future in a suspending context.
 ///
 /// A [Suspension] is created from the passed future and the result of the future
@@ -82,6 +85,43 @@ where
     use_future_with((), move |_| init_f())
 }
 
+/// Aborts the future of [`use_future_with`] once its result can no longer be used, if the
+/// component is rendered on the server.
+///
+/// On the client, futures run to completion, so their side effects are not cancelled.
+struct AbortOnDrop(Option<AbortHandle>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        if let Some(m) = self.0.take() {
+            m.abort();
+        }
+    }
+}
+
+/// Returns whether the component is rendered by a server renderer.
+fn use_is_server_render() -> impl Hook<Output = bool> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = bool;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            #[cfg(feature = "ssr")]
+            let server_render = ctx.is_server_render();
+            #[cfg(not(feature = "ssr"))]
+            let server_render = {
+                let _ = ctx;
+                false
+            };
+
+            server_render
+        }
+    }
+
+    HookProvider
+}
+
 /// Use the result of an async computation with dependencies, suspending while waiting.
 ///
 /// Awaits the future returned from `f` for the latest `deps`. Even if the future is immediately
@@ -89,6 +129,9 @@ where
 /// change while a future is still pending, the result is never used. This guarantees that your
 /// component always sees up-to-date values while it is not suspended.
 ///
+/// During server-side rendering, a future that is still pending when the component is destroyed,
+/// such as when the render is dropped before it has finished, is dropped as well.
+///
 /// [ready]: std::task::Poll::Ready
 #[hook]
 pub fn use_future_with<F, D, T, O>(deps: D, f: F) -> SuspensionResult<UseFutureHandle<O>>
@@ -99,6 +142,7 @@ where
     D: PartialEq + 'static,
 {
     let output = use_state(|| None);
+    let server_render = use_is_server_render();
     // We only commit a result if it comes from the latest spawned future. Otherwise, this
     // might trigger pointless updates or even override newer state.
     let latest_id = use_ref(|| Cell::new(0u32));
@@ -111,22 +155,25 @@ where
                 // As long as less than 2**32 futures are in flight wrapping_add is fine
                 (*latest_id).set(self_id);
                 let deps = Rc::new(deps);
-                let task = f(deps.clone());
+                let (task, abort_handle) = abortable(f(deps.clone()));
                 let suspension = Suspension::from_future(async move {
-                    let result = task.await;
-                    if latest_id.get() == self_id {
-                        output.set(Some(result));
+                    if let Ok(result) = task.await {
+                        if latest_id.get() == self_id {
+                            output.set(Some(result));
+                        }
                     }
                 });
-                (suspension, deps)
+                let abort = AbortOnDrop(server_render.then_some(abort_handle));
+                ((suspension, abort), deps)
             },
             deps,
         )
     };
 
+    let (suspension, _) = &*suspension;
     if suspension.resumed() {
         Ok(UseFutureHandle { inner: output })
     } else {
-        Err((*suspension).clone())
+        Err(suspension.clone())
     }
 }
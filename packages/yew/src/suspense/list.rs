@@ -0,0 +1,395 @@
+use crate::html::{Html, Properties};
+
+/// The order in which the boundaries of a [SuspenseList] reveal their content.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RevealOrder {
+    /// A boundary reveals its content once it and all boundaries before it have resolved.
+    #[default]
+    Forwards,
+    /// A boundary reveals its content once it and all boundaries after it have resolved.
+    Backwards,
+    /// All boundaries reveal their content at once when all of them have resolved.
+    Together,
+}
+
+/// Which fallbacks of the unrevealed boundaries of a [SuspenseList] are shown.
+///
+/// This only applies to [`RevealOrder::Forwards`] and [`RevealOrder::Backwards`]. Without a tail
+/// mode, the fallbacks of all unrevealed boundaries are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuspenseListTail {
+    /// Only the fallback of the next boundary to be revealed is shown.
+    Collapsed,
+    /// No fallbacks are shown.
+    Hidden,
+}
+
+/// Properties for [SuspenseList].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct SuspenseListProps {
+    /// The Children of the current SuspenseList Component.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// The order in which the Suspense boundaries reveal their content.
+    #[prop_or_default]
+    pub reveal_order: RevealOrder,
+
+    /// Which fallbacks of the unrevealed Suspense boundaries are shown.
+    #[prop_or_default]
+    pub tail: Option<SuspenseListTail>,
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+mod feat_csr_ssr {
+    use std::cmp::Ordering;
+    use std::iter;
+
+    #[cfg(feature = "csr")]
+    use web_sys::{Element, Node};
+
+    use super::*;
+    use crate::html::{AnyScope, Component, Context, Scope};
+    use crate::suspense::BaseSuspense;
+    use crate::{component, html};
+
+    /// Whether a boundary of a list may reveal its content.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum ListReveal {
+        /// The boundary shows its content once it has resolved.
+        #[default]
+        Content,
+        /// The boundary shows its fallback.
+        Fallback,
+        /// The boundary shows neither its content nor its fallback.
+        Hidden,
+    }
+
+    #[derive(Debug)]
+    pub(crate) enum BaseSuspenseListMsg {
+        Register(Scope<BaseSuspense>, bool),
+        Update(usize, bool),
+        Unregister(usize),
+    }
+
+    #[derive(Debug)]
+    struct Boundary {
+        scope: Scope<BaseSuspense>,
+        resolved: bool,
+        reveal: ListReveal,
+    }
+
+    /// Coordinates the [BaseSuspense] boundaries rendered in its children.
+    ///
+    /// Boundaries are ordered by their position in the document, so boundaries inserted after the
+    /// list has been rendered take their place among the others. During server-side rendering,
+    /// they are ordered by the time they are created, which is the order they appear in.
+    #[derive(Debug)]
+    pub(crate) struct BaseSuspenseList {
+        boundaries: Vec<Boundary>,
+    }
+
+    impl Component for BaseSuspenseList {
+        type Message = BaseSuspenseListMsg;
+        type Properties = SuspenseListProps;
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                boundaries: Vec::new(),
+            }
+        }
+
+        fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                BaseSuspenseListMsg::Register(scope, resolved) => {
+                    self.boundaries.push(Boundary {
+                        scope,
+                        resolved,
+                        reveal: ListReveal::Content,
+                    });
+                }
+                BaseSuspenseListMsg::Update(id, resolved) => {
+                    if let Some(m) = self.boundaries.iter_mut().find(|m| m.scope.id == id) {
+                        m.resolved = resolved;
+                    }
+                }
+                BaseSuspenseListMsg::Unregister(id) => {
+                    self.boundaries.retain(|m| m.scope.id != id);
+                }
+            }
+
+            self.reveal(ctx.props());
+
+            false
+        }
+
+        fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+            self.reveal(ctx.props());
+
+            true
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            ctx.props().children.clone()
+        }
+    }
+
+    impl BaseSuspenseList {
+        /// Finds the list coordinating a boundary rendered by `parent`.
+        ///
+        /// Only the boundaries that are not nested in another boundary of the list are
+        /// coordinated.
+        pub(crate) fn find(parent: Option<&AnyScope>) -> Option<Scope<Self>> {
+            iter::successors(parent, |m| m.get_parent())
+                .find_map(|m| match m.try_downcast::<Self>() {
+                    Some(list) => Some(Some(list)),
+                    None => m.try_downcast::<BaseSuspense>().map(|_| None),
+                })
+                .flatten()
+        }
+
+        /// Notifies the boundaries whose reveal state has changed.
+        fn reveal(&mut self, props: &SuspenseListProps) {
+            #[cfg(feature = "csr")]
+            self.boundaries.sort_by(|lhs, rhs| {
+                match (lhs.scope.dom_position(), rhs.scope.dom_position()) {
+                    (Some(lhs), Some(rhs)) => cmp_dom_positions(&lhs, &rhs),
+                    _ => Ordering::Equal,
+                }
+            });
+
+            let reveals = Self::compute(
+                self.boundaries.iter().map(|m| m.resolved),
+                props.reveal_order,
+                props.tail,
+            );
+
+            for (boundary, reveal) in self.boundaries.iter_mut().zip(reveals) {
+                if boundary.reveal != reveal {
+                    boundary.reveal = reveal;
+                    BaseSuspense::reveal(&boundary.scope, reveal);
+                }
+            }
+        }
+
+        fn compute(
+            resolved: impl DoubleEndedIterator<Item = bool> + ExactSizeIterator,
+            order: RevealOrder,
+            tail: Option<SuspenseListTail>,
+        ) -> Vec<ListReveal> {
+            let resolved: Vec<bool> = match order {
+                RevealOrder::Backwards => resolved.rev().collect(),
+                _ => resolved.collect(),
+            };
+
+            let mut reveals = match order {
+                RevealOrder::Together => {
+                    let reveal = if resolved.iter().all(|m| *m) {
+                        ListReveal::Content
+                    } else {
+                        ListReveal::Fallback
+                    };
+
+                    vec![reveal; resolved.len()]
+                }
+                RevealOrder::Forwards | RevealOrder::Backwards => {
+                    let revealed = resolved.iter().take_while(|m| **m).count();
+
+                    (0..resolved.len())
+                        .map(|i| match (i.cmp(&revealed), tail) {
+                            (Ordering::Less, _) => ListReveal::Content,
+                            (_, None) => ListReveal::Fallback,
+                            (Ordering::Equal, Some(SuspenseListTail::Collapsed)) => {
+                                ListReveal::Fallback
+                            }
+                            (_, Some(_)) => ListReveal::Hidden,
+                        })
+                        .collect()
+                }
+            };
+
+            if order == RevealOrder::Backwards {
+                reveals.reverse();
+            }
+
+            reveals
+        }
+    }
+
+    /// Compares two positions in the document, each given by a parent element and the node in front
+    /// of which the position is, or `None` for the end of the parent.
+    #[cfg(feature = "csr")]
+    fn cmp_dom_positions(
+        (lhs_parent, lhs): &(Element, Option<Node>),
+        (rhs_parent, rhs): &(Element, Option<Node>),
+    ) -> Ordering {
+        // Whether `rhs` comes after `lhs` in the document, which includes the descendants of `lhs`.
+        let follows = |lhs: &Node, rhs: &Node| {
+            lhs.compare_document_position(rhs) & Node::DOCUMENT_POSITION_FOLLOWING != 0
+        };
+
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) if lhs == rhs => Ordering::Equal,
+            (Some(lhs), Some(rhs)) if follows(lhs, rhs) => Ordering::Less,
+            (Some(_), Some(_)) => Ordering::Greater,
+            // The end of a parent is after all of its descendants.
+            (Some(lhs), None) if rhs_parent.contains(Some(lhs)) || follows(lhs, rhs_parent) => {
+                Ordering::Less
+            }
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => cmp_dom_positions(
+                &(rhs_parent.clone(), rhs.clone()),
+                &(lhs_parent.clone(), lhs.clone()),
+            )
+            .reverse(),
+            (None, None) if lhs_parent == rhs_parent => Ordering::Equal,
+            (None, None) if lhs_parent.contains(Some(rhs_parent)) => Ordering::Greater,
+            (None, None)
+                if rhs_parent.contains(Some(lhs_parent)) || follows(lhs_parent, rhs_parent) =>
+            {
+                Ordering::Less
+            }
+            (None, None) => Ordering::Greater,
+        }
+    }
+
+    /// Coordinates the order in which the [Suspense](crate::suspense::Suspense) boundaries in its
+    /// children reveal their content.
+    ///
+    /// Boundaries nested in another boundary of the list are not coordinated by it.
+    ///
+    /// During server-side rendering, the boundaries of a list are always rendered in place, so the
+    /// document contains the content of all of them at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// # use yew::suspense::{RevealOrder, SuspenseList, SuspenseListTail};
+    /// # #[component]
+    /// # fn Post() -> Html { Html::default() }
+    /// #[component]
+    /// fn Feed() -> Html {
+    ///     let fallback = html! { <div>{"Loading..."}</div> };
+    ///
+    ///     html! {
+    ///         <SuspenseList reveal_order={RevealOrder::Forwards} tail={SuspenseListTail::Collapsed}>
+    ///             <Suspense fallback={fallback.clone()}>
+    ///                 <Post />
+    ///             </Suspense>
+    ///             <Suspense {fallback}>
+    ///                 <Post />
+    ///             </Suspense>
+    ///         </SuspenseList>
+    ///     }
+    /// }
+    /// ```
+    #[component]
+    pub fn SuspenseList(props: &SuspenseListProps) -> Html {
+        let props = props.clone();
+
+        html! {
+            <BaseSuspenseList ..props />
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::ListReveal::*;
+        use super::*;
+
+        fn compute(
+            resolved: &[bool],
+            order: RevealOrder,
+            tail: Option<SuspenseListTail>,
+        ) -> Vec<ListReveal> {
+            BaseSuspenseList::compute(resolved.iter().copied(), order, tail)
+        }
+
+        #[test]
+        fn test_reveal_order() {
+            let resolved = [true, false, true, false];
+
+            assert_eq!(
+                compute(&resolved, RevealOrder::Forwards, None),
+                [Content, Fallback, Fallback, Fallback]
+            );
+            assert_eq!(
+                compute(&resolved, RevealOrder::Backwards, None),
+                [Fallback, Fallback, Fallback, Fallback]
+            );
+            assert_eq!(
+                compute(&[false, true, true], RevealOrder::Backwards, None),
+                [Fallback, Content, Content]
+            );
+            assert_eq!(
+                compute(&resolved, RevealOrder::Together, None),
+                [Fallback, Fallback, Fallback, Fallback]
+            );
+            assert_eq!(
+                compute(&[true, true], RevealOrder::Together, None),
+                [Content, Content]
+            );
+        }
+
+        #[test]
+        fn test_reveal_tail() {
+            let resolved = [true, false, true, false];
+
+            assert_eq!(
+                compute(
+                    &resolved,
+                    RevealOrder::Forwards,
+                    Some(SuspenseListTail::Collapsed)
+                ),
+                [Content, Fallback, Hidden, Hidden]
+            );
+            assert_eq!(
+                compute(
+                    &resolved,
+                    RevealOrder::Forwards,
+                    Some(SuspenseListTail::Hidden)
+                ),
+                [Content, Hidden, Hidden, Hidden]
+            );
+            assert_eq!(
+                compute(
+                    &resolved,
+                    RevealOrder::Backwards,
+                    Some(SuspenseListTail::Collapsed)
+                ),
+                [Hidden, Hidden, Hidden, Fallback]
+            );
+            // The tail mode does not apply when revealing all boundaries together.
+            assert_eq!(
+                compute(
+                    &resolved,
+                    RevealOrder::Together,
+                    Some(SuspenseListTail::Hidden)
+                ),
+                [Fallback, Fallback, Fallback, Fallback]
+            );
+        }
+    }
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub use feat_csr_ssr::SuspenseList;
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) use feat_csr_ssr::{BaseSuspenseList, BaseSuspenseListMsg, ListReveal};
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+mod feat_no_csr_ssr {
+    use super::*;
+    use crate::component;
+
+    /// Coordinates the order in which the [Suspense](crate::suspense::Suspense) boundaries in its
+    /// children reveal their content.
+    #[component]
+    pub fn SuspenseList(_props: &SuspenseListProps) -> Html {
+        Html::default()
+    }
+}
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+pub use feat_no_csr_ssr::*;
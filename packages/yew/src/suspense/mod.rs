@@ -2,10 +2,22 @@
 
 mod component;
 mod hooks;
+mod list;
+#[cfg(feature = "ssr")]
+mod streaming;
 mod suspension;
+mod transition;
 
 #[cfg(any(feature = "csr", feature = "ssr"))]
 pub(crate) use component::BaseSuspense;
 pub use component::{Suspense, SuspenseProps};
 pub use hooks::*;
+#[cfg(feature = "ssr")]
+pub(crate) use list::BaseSuspenseList;
+pub use list::{RevealOrder, SuspenseList, SuspenseListProps, SuspenseListTail};
+#[cfg(feature = "ssr")]
+pub(crate) use streaming::{Deadline, DeferredBoundary, DeferredRender};
 pub use suspension::{Suspension, SuspensionHandle, SuspensionResult};
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) use transition::Transition;
+pub use transition::{StartTransition, use_transition};
@@ -0,0 +1,289 @@
+//! Out-of-order streaming of suspended subtrees during server-side rendering.
+//!
+//! When enabled, a [`Suspense`](super::Suspense) whose children are not ready when it is rendered
+//! writes its fallback between two placeholder templates and lets the rest of the document
+//! continue. Once the shell has been written, the children of each boundary are appended to the
+//! stream in the order they resolve as a `<template>` and an inline script that swaps the
+//! fallback with them.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::future::{Future, poll_fn};
+use std::iter;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+use std::time::Duration;
+
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use futures::stream::{FuturesUnordered, StreamExt};
+
+use crate::feat_ssr::RenderState;
+use crate::html::AnyScope;
+use crate::platform::fmt::BufWriter;
+use crate::platform::time::sleep;
+use crate::scheduler::{self, Runnable};
+use crate::virtual_dom::Collectable;
+
+/// Replaces the fallback of a pending boundary with its content.
+///
+/// The boundary is left untouched if it has been hydrated before its content arrived. The ids
+/// must match the ones in [`Collectable`].
+const SWAP_SCRIPT: &str = concat!(
+    "<script>",
+    "function __yew_swap(i){",
+    "var d=document,s=d.getElementById(\"yew-sb-\"+i),e=d.getElementById(\"yew-se-\"+i),",
+    "c=d.getElementById(\"yew-sc-\"+i);",
+    "if(s&&e&&c){var p=e.parentNode;",
+    "while(s.nextSibling&&s.nextSibling!==e)p.removeChild(s.nextSibling);",
+    "p.removeChild(s);p.replaceChild(c.content,e)}",
+    "if(c)c.remove();d.currentScript.remove()}",
+    "document.currentScript.remove()",
+    "</script>"
+);
+
+/// The resolved content of a boundary.
+struct Chunk {
+    id: usize,
+    parent: Option<usize>,
+    html: String,
+}
+
+/// A handle to a boundary of a render with out-of-order streaming.
+pub(crate) struct DeferredBoundary {
+    render: Rc<DeferredRender>,
+    id: usize,
+    parent: Option<usize>,
+}
+
+impl DeferredBoundary {
+    /// Creates a boundary for the suspense rendered by `scope` if it belongs to a render with
+    /// out-of-order streaming.
+    pub fn find(scope: &AnyScope) -> Option<Self> {
+        let render = RenderState::find(scope)?.deferred.clone()?;
+        let ids: Vec<_> = iter::successors(Some(scope), |m| m.get_parent())
+            .map(|m| m.id)
+            .collect();
+
+        let id = render.next_id.get();
+        render.next_id.set(id + 1);
+
+        // The boundary this one is nested in, if it is rendered as part of deferred content.
+        let parent = {
+            let mut boundaries = render.boundaries.borrow_mut();
+            let parent = ids.iter().find_map(|m| boundaries.get(m).copied());
+            boundaries.insert(scope.id, id);
+            parent
+        };
+
+        Some(Self { render, id, parent })
+    }
+
+    /// Returns the id of the boundary.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Marks the content of the boundary as written in place.
+    pub fn write_in_place(self) {
+        self.render.written.borrow_mut().insert(self.id);
+    }
+
+    /// Defers the content of the boundary until `content` resolves.
+    pub fn defer<F>(self, content: F)
+    where
+        F: 'static + Future<Output = String>,
+    {
+        let Self { render, id, parent } = self;
+
+        render.incoming.borrow_mut().push(Box::pin(async move {
+            let html = content.await;
+            Chunk { id, parent, html }
+        }));
+        render.wake();
+    }
+}
+
+/// The state of a render with out-of-order streaming enabled.
+pub(crate) struct DeferredRender {
+    next_id: Cell<usize>,
+    /// The ids of boundaries, keyed by the id of the scope rendering them.
+    boundaries: RefCell<HashMap<usize, usize>>,
+    /// The ids of boundaries whose content is part of the document.
+    written: RefCell<HashSet<usize>>,
+    incoming: RefCell<Vec<LocalBoxFuture<'static, Chunk>>>,
+    /// Components are destroyed once the entire document has been rendered, as deferred content
+    /// may still need to access its ancestors.
+    destroys: RefCell<Vec<Box<dyn Runnable>>>,
+    shell_rendered: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl DeferredRender {
+    pub fn new() -> Self {
+        Self {
+            next_id: Cell::new(0),
+            boundaries: RefCell::default(),
+            written: RefCell::default(),
+            incoming: RefCell::default(),
+            destroys: RefCell::default(),
+            shell_rendered: Cell::new(false),
+            waker: RefCell::default(),
+        }
+    }
+
+    /// Destroys a component once rendering has finished if `scope` belongs to a render with
+    /// out-of-order streaming.
+    ///
+    /// Returns the runnable if the component should be destroyed immediately.
+    pub fn destroy_later(
+        scope: &AnyScope,
+        runnable: Box<dyn Runnable>,
+    ) -> Option<Box<dyn Runnable>> {
+        match RenderState::find(scope).and_then(|m| m.deferred.clone()) {
+            Some(m) => {
+                m.destroys.borrow_mut().push(runnable);
+                None
+            }
+            None => Some(runnable),
+        }
+    }
+
+    /// Marks the shell as rendered.
+    ///
+    /// No boundaries are deferred by the shell after this point.
+    pub fn finish_shell(&self) {
+        self.shell_rendered.set(true);
+        self.wake();
+    }
+
+    fn wake(&self) {
+        if let Some(m) = self.waker.borrow_mut().take() {
+            m.wake();
+        }
+    }
+
+    /// Renders deferred boundaries into `w` as they resolve.
+    ///
+    /// The content of a nested boundary is only written after the content of its parent, as its
+    /// placeholder is not part of the document before that. Boundaries that have not resolved
+    /// when the `deadline` is reached keep their fallback.
+    pub async fn render_deferred(&self, w: &mut BufWriter, deadline: Option<Deadline>) {
+        let mut pending = FuturesUnordered::new();
+        let mut waiting = Vec::new();
+        let mut script_written = false;
+        let mut deadline = deadline.map(|m| m.reached());
+
+        poll_fn(|cx| {
+            loop {
+                if deadline
+                    .as_mut()
+                    .is_some_and(|m| m.poll_unpin(cx).is_ready())
+                {
+                    return Poll::Ready(());
+                }
+
+                pending.extend(self.incoming.borrow_mut().drain(..));
+
+                match pending.poll_next_unpin(cx) {
+                    Poll::Ready(Some(m)) => {
+                        waiting.push(m);
+
+                        let mut written = self.written.borrow_mut();
+                        while let Some(index) = waiting
+                            .iter()
+                            .position(|m: &Chunk| m.parent.is_none_or(|p| written.contains(&p)))
+                        {
+                            let chunk = waiting.swap_remove(index);
+                            if !script_written {
+                                let _ = w.write_str(SWAP_SCRIPT);
+                                script_written = true;
+                            }
+                            write_chunk(w, &chunk);
+                            written.insert(chunk.id);
+                        }
+                    }
+                    Poll::Ready(None) | Poll::Pending => {
+                        if !self.incoming.borrow().is_empty() {
+                            continue;
+                        }
+
+                        if pending.is_empty() && self.shell_rendered.get() {
+                            return Poll::Ready(());
+                        }
+
+                        *self.waker.borrow_mut() = Some(cx.waker().clone());
+                        return Poll::Pending;
+                    }
+                }
+            }
+        })
+        .await;
+    }
+
+    /// Destroys all components of the render.
+    pub fn finish(&self) {
+        for m in self.destroys.take() {
+            scheduler::push_component_destroy(m);
+        }
+        scheduler::start();
+    }
+}
+
+impl Drop for DeferredRender {
+    fn drop(&mut self) {
+        // The render has been dropped before it has finished.
+        if !self.destroys.borrow().is_empty() {
+            self.finish();
+        }
+    }
+}
+
+/// The deadline of a render, after which suspended boundaries are written with their fallback.
+#[derive(Clone)]
+pub(crate) struct Deadline {
+    timer: Shared<LocalBoxFuture<'static, ()>>,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl Deadline {
+    /// Creates a deadline that is reached after `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timer: sleep(timeout).boxed_local().shared(),
+            next_id: Rc::default(),
+        }
+    }
+
+    /// Finds the deadline of the render the component at `scope` belongs to.
+    pub fn find(scope: &AnyScope) -> Option<Self> {
+        RenderState::find(scope)?.deadline.clone()
+    }
+
+    /// Resolves once the deadline has been reached.
+    pub fn reached(&self) -> Shared<LocalBoxFuture<'static, ()>> {
+        self.timer.clone()
+    }
+
+    /// Returns a new id for a boundary that has timed out.
+    ///
+    /// The ids are distinct from the ids of boundaries streamed out of order, so the client
+    /// never finds content for them and renders their children during hydration.
+    pub fn next_id(&self) -> String {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+
+        format!("t{id}")
+    }
+}
+
+fn write_chunk(w: &mut BufWriter, chunk: &Chunk) {
+    let _ = write!(
+        w,
+        r#"<template id="{}{}">{}</template><script>__yew_swap({})</script>"#,
+        Collectable::SUSPENSE_CONTENT_ID_PREFIX,
+        chunk.id,
+        chunk.html,
+        chunk.id
+    );
+}
@@ -0,0 +1,191 @@
+//! Transitions keep the content of a [`Suspense`](super::Suspense) visible while an update
+//! suspends.
+
+use std::fmt;
+use std::rc::Rc;
+
+use crate::functional::{Reducible, UseReducerDispatcher, hook, use_reducer};
+
+/// A pending transition.
+///
+/// A transition is pending as long as any of its clones is alive: components whose renders
+/// have been scheduled in the transition and the suspense boundaries holding back its
+/// suspensions keep one.
+#[derive(Clone)]
+pub(crate) struct Transition {
+    _inner: Rc<TransitionInner>,
+}
+
+struct TransitionInner {
+    settle: Box<dyn Fn()>,
+}
+
+impl Drop for TransitionInner {
+    fn drop(&mut self) {
+        (self.settle)();
+    }
+}
+
+impl fmt::Debug for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transition<_>")
+    }
+}
+
+#[cfg(feature = "csr")]
+mod feat_csr {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use super::*;
+
+    thread_local! {
+        /// The transition updates are currently dispatched in.
+        static CURRENT: RefCell<Option<Transition>> = const { RefCell::new(None) };
+        /// The transitions of components with scheduled renders, keyed by component id.
+        static MARKS: RefCell<HashMap<usize, Transition>> = RefCell::new(HashMap::new());
+    }
+
+    impl Transition {
+        /// Runs `f` with `transition` as the current transition.
+        pub(crate) fn enter<R>(transition: Option<Transition>, f: impl FnOnce() -> R) -> R {
+            let prev = CURRENT.with(|m| m.replace(transition));
+            let result = f();
+            // Dropped outside of the borrow, as settling a transition dispatches an update.
+            let _transition = CURRENT.with(|m| m.replace(prev));
+
+            result
+        }
+
+        /// Marks the component with the current transition, if any.
+        pub(crate) fn mark(comp_id: usize) {
+            if let Some(m) = CURRENT.with(|m| m.borrow().clone()) {
+                let _prev = MARKS.with(|marks| marks.borrow_mut().insert(comp_id, m));
+            }
+        }
+
+        /// Takes the transition the component has been marked with.
+        pub(crate) fn take(comp_id: usize) -> Option<Transition> {
+            MARKS.with(|marks| marks.borrow_mut().remove(&comp_id))
+        }
+
+        /// Removes the marks of components that have not rendered.
+        ///
+        /// Called once the scheduler has run all pending work.
+        pub(crate) fn clear_marks() {
+            let _marks = MARKS.with(|marks| marks.take());
+        }
+    }
+}
+
+/// A handle to start a transition, returned by [`use_transition`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartTransition {
+    dispatcher: UseReducerDispatcher<PendingTransitions>,
+}
+
+impl StartTransition {
+    /// Runs `f`, dispatching all updates in it as part of a transition.
+    ///
+    /// The renders caused by these updates do not replace the content of a
+    /// [`Suspense`](super::Suspense) with its fallback when they suspend. Instead, the previously
+    /// committed content stays visible until the suspensions have resolved.
+    pub fn start<F>(&self, f: F)
+    where
+        F: FnOnce(),
+    {
+        self.dispatcher.dispatch(PendingAction::Start);
+
+        let dispatcher = self.dispatcher.clone();
+        let transition = Transition {
+            _inner: Rc::new(TransitionInner {
+                settle: Box::new(move || dispatcher.dispatch(PendingAction::Settle)),
+            }),
+        };
+
+        #[cfg(feature = "csr")]
+        Transition::enter(Some(transition), f);
+
+        #[cfg(not(feature = "csr"))]
+        {
+            f();
+            drop(transition);
+        }
+    }
+}
+
+enum PendingAction {
+    Start,
+    Settle,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct PendingTransitions(usize);
+
+impl Reducible for PendingTransitions {
+    type Action = PendingAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            PendingAction::Start => Self(self.0 + 1).into(),
+            PendingAction::Settle => Self(self.0.saturating_sub(1)).into(),
+        }
+    }
+}
+
+/// This hook is used to update state without replacing already visible content with the fallback
+/// of a [`Suspense`](super::Suspense).
+///
+/// It returns whether a transition is pending and a [`StartTransition`] handle. Updates
+/// dispatched in [`StartTransition::start`] keep the content of the nearest `Suspense` visible
+/// while the components they re-render suspend. The transition remains pending until all of
+/// these suspensions have resolved.
+///
+/// A component that suspends when it is rendered for the first time has no previous content,
+/// so it renders nothing until it resumes. To keep content visible, re-render an existing
+/// component with new properties instead of replacing it.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use yew::suspense::use_transition;
+/// #[derive(Properties, PartialEq)]
+/// struct TabProps {
+///     tab: usize,
+/// }
+///
+/// # #[component]
+/// # fn TabContent(props: &TabProps) -> Html { Html::default() }
+/// #[component]
+/// fn Tabs() -> Html {
+///     let tab = use_state(|| 0);
+///     let (is_pending, start_transition) = use_transition();
+///
+///     let onclick = {
+///         let tab = tab.clone();
+///         Callback::from(move |_| {
+///             let tab = tab.clone();
+///             start_transition.start(move || tab.set(*tab + 1));
+///         })
+///     };
+///
+///     html! {
+///         <div class={classes!(is_pending.then_some("pending"))}>
+///             <button {onclick}>{"Next"}</button>
+///             <Suspense fallback={html! {"Loading..."}}>
+///                 <TabContent tab={*tab} />
+///             </Suspense>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_transition() -> (bool, StartTransition) {
+    let pending = use_reducer(PendingTransitions::default);
+    let start_transition = StartTransition {
+        dispatcher: pending.dispatcher(),
+    };
+
+    (pending.0 > 0, start_transition)
+}
@@ -73,6 +73,17 @@ mod feat_ssr_hydration {
     }
 
     impl Collectable {
+        /// The prefix of the id of the template holding the content of a suspense streamed out
+        /// of order.
+        pub const SUSPENSE_CONTENT_ID_PREFIX: &'static str = "yew-sc-";
+        /// The prefix of the id of the template marking the end of a suspense streamed out of
+        /// order.
+        #[cfg(feature = "ssr")]
+        pub const SUSPENSE_END_ID_PREFIX: &'static str = "yew-se-";
+        /// The prefix of the id of the template marking the start of a suspense streamed out of
+        /// order.
+        pub const SUSPENSE_START_ID_PREFIX: &'static str = "yew-sb-";
+
         #[cfg(not(debug_assertions))]
         #[inline(always)]
         pub fn for_component<T: 'static>() -> Self {
@@ -6,6 +6,8 @@ use std::rc::Rc;
 
 #[cfg(feature = "ssr")]
 use futures::future::{FutureExt, LocalBoxFuture};
+#[cfg(feature = "ssr")]
+use futures::stream::StreamExt;
 #[cfg(feature = "csr")]
 use web_sys::Element;
 
@@ -20,7 +22,9 @@ use crate::html::Scoped;
 #[cfg(any(feature = "ssr", feature = "csr"))]
 use crate::html::{AnyScope, Scope};
 #[cfg(feature = "ssr")]
-use crate::{feat_ssr::VTagKind, platform::fmt::BufWriter};
+use crate::platform::fmt::BufWriter;
+#[cfg(feature = "ssr")]
+use crate::{feat_ssr::VTagKind, render_cache::CacheEntry};
 
 /// A virtual component.
 pub struct VComp {
@@ -92,11 +96,18 @@ pub(crate) trait Mountable {
 
 pub(crate) struct PropsWrapper<COMP: BaseComponent> {
     props: Rc<COMP::Properties>,
+    /// The key the rendered markup of the component is cached with during server-side rendering.
+    #[cfg(feature = "ssr")]
+    cache_key: Option<Rc<str>>,
 }
 
 impl<COMP: BaseComponent> PropsWrapper<COMP> {
     pub fn new(props: Rc<COMP::Properties>) -> Self {
-        Self { props }
+        Self {
+            props,
+            #[cfg(feature = "ssr")]
+            cache_key: None,
+        }
     }
 }
 
@@ -104,6 +115,8 @@ impl<COMP: BaseComponent> Mountable for PropsWrapper<COMP> {
     fn copy(&self) -> Box<dyn Mountable> {
         let wrapper: PropsWrapper<COMP> = PropsWrapper {
             props: Rc::clone(&self.props),
+            #[cfg(feature = "ssr")]
+            cache_key: self.cache_key.clone(),
         };
         Box::new(wrapper)
     }
@@ -147,12 +160,45 @@ impl<COMP: BaseComponent> Mountable for PropsWrapper<COMP> {
         hydratable: bool,
         parent_vtag_kind: VTagKind,
     ) -> LocalBoxFuture<'a, ()> {
-        let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
-
         async move {
+            // The scope takes its position in the tree even if the markup is replayed.
+            let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
+
+            let cache_entry = self.cache_key.as_deref().and_then(|key| {
+                CacheEntry::find(
+                    &scope.clone().into(),
+                    std::any::type_name::<COMP>(),
+                    key,
+                    hydratable,
+                    parent_vtag_kind,
+                )
+            });
+            let Some(cache_entry) = cache_entry else {
+                scope
+                    .render_into_stream(w, self.props.clone(), hydratable, parent_vtag_kind)
+                    .await;
+                return;
+            };
+
+            if let Some(html) = cache_entry.get() {
+                let _ = fmt::Write::write_str(w, &html);
+                return;
+            }
+
+            let (mut cache_w, cache_r) = crate::platform::fmt::buffer();
             scope
-                .render_into_stream(w, self.props.clone(), hydratable, parent_vtag_kind)
+                .render_into_stream(
+                    &mut cache_w,
+                    self.props.clone(),
+                    hydratable,
+                    parent_vtag_kind,
+                )
                 .await;
+            drop(cache_w);
+
+            let html = cache_r.collect::<String>().await;
+            let _ = fmt::Write::write_str(w, &html);
+            cache_entry.insert(html);
         }
         .boxed_local()
     }
@@ -248,6 +294,21 @@ impl VComp {
             _marker: 0,
         }
     }
+
+    /// Creates a new `VComp` instance whose rendered markup is cached with `cache_key` during
+    /// server-side rendering.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn new_cached<COMP>(props: Rc<COMP::Properties>, cache_key: Option<Rc<str>>) -> Self
+    where
+        COMP: BaseComponent,
+    {
+        VComp {
+            type_id: TypeId::of::<COMP>(),
+            mountable: Box::new(PropsWrapper::<COMP> { props, cache_key }),
+            key: None,
+            _marker: 0,
+        }
+    }
 }
 
 impl PartialEq for VComp {
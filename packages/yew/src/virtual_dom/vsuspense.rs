@@ -27,10 +27,16 @@ impl VSuspense {
 
 #[cfg(feature = "ssr")]
 mod feat_ssr {
+    use std::fmt::Write;
+
+    use futures::future::{Either, select};
+    use futures::{FutureExt, StreamExt, pin_mut, poll};
+
     use super::*;
     use crate::feat_ssr::VTagKind;
     use crate::html::AnyScope;
-    use crate::platform::fmt::BufWriter;
+    use crate::platform::fmt::{self, BufWriter};
+    use crate::suspense::{BaseSuspenseList, Deadline, DeferredBoundary};
     use crate::virtual_dom::Collectable;
 
     impl VSuspense {
@@ -47,15 +53,138 @@ mod feat_ssr {
                 collectable.write_open_tag(w);
             }
 
-            // always render children on the server side.
-            self.children
-                .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
-                .await;
+            // Templates cannot be placed inside of <style> or <script> tags. Boundaries
+            // coordinated by a list are rendered in place to preserve their reveal order.
+            let deferred = match parent_vtag_kind {
+                VTagKind::Other if BaseSuspenseList::find(parent_scope.get_parent()).is_none() => {
+                    DeferredBoundary::find(parent_scope)
+                }
+                _ => None,
+            };
+
+            match (deferred, Deadline::find(parent_scope)) {
+                (Some(m), _) => {
+                    self.render_out_of_order(w, parent_scope, hydratable, m)
+                        .await
+                }
+                // The client needs markers to tell a fallback from the children, which cannot
+                // be placed inside of <style> or <script> tags either, so the children of these
+                // boundaries are always awaited.
+                (None, Some(m)) if !hydratable || matches!(parent_vtag_kind, VTagKind::Other) => {
+                    self.render_until(w, parent_scope, hydratable, parent_vtag_kind, m)
+                        .await
+                }
+                _ => {
+                    // always render children on the server side.
+                    self.children
+                        .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                        .await;
+                }
+            }
 
             if hydratable {
                 collectable.write_close_tag(w);
             }
         }
+
+        /// Renders the children if they are ready before the `deadline`, or the fallback in
+        /// their place otherwise.
+        async fn render_until(
+            &self,
+            w: &mut BufWriter,
+            parent_scope: &AnyScope,
+            hydratable: bool,
+            parent_vtag_kind: VTagKind,
+            deadline: Deadline,
+        ) {
+            let (mut children_w, children_r) = fmt::buffer();
+            let children = async move {
+                self.children
+                    .render_into_stream(&mut children_w, parent_scope, hydratable, parent_vtag_kind)
+                    .await;
+            };
+            pin_mut!(children);
+
+            if let Either::Left(_) = select(children, deadline.reached()).await {
+                pin_mut!(children_r);
+                while let Some(m) = children_r.next().await {
+                    let _ = w.write_str(m.as_str());
+                }
+
+                return;
+            }
+
+            let id = deadline.next_id();
+            if hydratable {
+                let _ = write!(
+                    w,
+                    r#"<template id="{}{id}"></template>"#,
+                    Collectable::SUSPENSE_START_ID_PREFIX
+                );
+            }
+            // The children are rendered on the client during hydration.
+            self.fallback
+                .render_into_stream(w, parent_scope, false, parent_vtag_kind)
+                .await;
+            if hydratable {
+                let _ = write!(
+                    w,
+                    r#"<template id="{}{id}"></template>"#,
+                    Collectable::SUSPENSE_END_ID_PREFIX
+                );
+            }
+        }
+
+        /// Renders the children if they are ready, or the fallback in their place otherwise.
+        async fn render_out_of_order(
+            &self,
+            w: &mut BufWriter,
+            parent_scope: &AnyScope,
+            hydratable: bool,
+            deferred: DeferredBoundary,
+        ) {
+            let (mut children_w, children_r) = fmt::buffer();
+            let children = self.children.clone();
+            let scope = parent_scope.clone();
+            let mut children_fur = async move {
+                children
+                    .render_into_stream(&mut children_w, &scope, hydratable, VTagKind::Other)
+                    .await;
+            }
+            .boxed_local();
+
+            if poll!(children_fur.as_mut()).is_ready() {
+                deferred.write_in_place();
+
+                pin_mut!(children_r);
+                while let Some(m) = children_r.next().await {
+                    let _ = w.write_str(m.as_str());
+                }
+
+                return;
+            }
+
+            let id = deferred.id();
+            deferred.defer(async move {
+                children_fur.await;
+                children_r.collect().await
+            });
+
+            let _ = write!(
+                w,
+                r#"<template id="{}{id}"></template>"#,
+                Collectable::SUSPENSE_START_ID_PREFIX
+            );
+            // The fallback is replaced once the children are ready, it is never hydrated.
+            self.fallback
+                .render_into_stream(w, parent_scope, false, VTagKind::Other)
+                .await;
+            let _ = write!(
+                w,
+                r#"<template id="{}{id}"></template>"#,
+                Collectable::SUSPENSE_END_ID_PREFIX
+            );
+        }
     }
 }
 
@@ -157,4 +286,252 @@ mod ssr_tests {
             "<div>Hello, Jane!</div><div>Hello, John!</div><div>Hello, Josh!</div>"
         );
     }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_suspense_out_of_order() {
+        #[derive(PartialEq, Properties, Debug)]
+        struct ChildProps {
+            name: String,
+            delay: u64,
+        }
+
+        #[component]
+        fn Child(props: &ChildProps) -> HtmlResult {
+            let delay = props.delay;
+            let state = use_state(|| {
+                let (s, handle) = Suspension::new();
+
+                spawn_local(async move {
+                    sleep(Duration::from_millis(delay)).await;
+
+                    handle.resume();
+                });
+
+                s
+            });
+
+            if !state.resumed() {
+                return Err((*state).clone().into());
+            }
+
+            Ok(html! { <div>{"Hello, "}{&props.name}{"!"}</div> })
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! {"loading Jane..."}}>
+                    <Child name="Jane" delay={100} />
+                    <Suspense fallback={html! {"loading John..."}}>
+                        <Child name="John" delay={10} />
+                    </Suspense>
+                </Suspense>
+                <Suspense fallback={html! {"loading Josh..."}}>
+                    <Child name="Josh" delay={50} />
+                </Suspense>
+                <div>{"Done!"}</div>
+            }
+        }
+
+        let local = LocalSet::new();
+
+        let s = local
+            .run_until(async move {
+                ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .out_of_order(true)
+                    .render()
+                    .await
+            })
+            .await;
+
+        let (shell, deferred) = s.split_once("<script>").unwrap();
+        assert_eq!(
+            shell,
+            concat!(
+                r#"<template id="yew-sb-0"></template>loading Jane...<template id="yew-se-0"></template>"#,
+                r#"<template id="yew-sb-2"></template>loading Josh...<template id="yew-se-2"></template>"#,
+                "<div>Done!</div>",
+            )
+        );
+
+        // The boundary of Josh resolves first, John is nested in the boundary of Jane.
+        let josh = deferred
+            .find(r#"<template id="yew-sc-2"><div>Hello, Josh!</div></template>"#)
+            .unwrap();
+        let jane = deferred
+            .find(concat!(
+                r#"<template id="yew-sc-0"><div>Hello, Jane!</div>"#,
+                r#"<template id="yew-sb-1"></template>loading John...<template id="yew-se-1"></template>"#,
+                "</template>",
+            ))
+            .unwrap();
+        let john = deferred
+            .find(r#"<template id="yew-sc-1"><div>Hello, John!</div></template>"#)
+            .unwrap();
+        assert!(josh < jane && jane < john);
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_suspense_out_of_order_ready() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! {"loading..."}}>
+                    <div>{"Hello!"}</div>
+                </Suspense>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .out_of_order(true)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Hello!</div>");
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_suspense_list_renders_in_place() {
+        use crate::suspense::SuspenseList;
+
+        #[derive(PartialEq, Properties, Debug)]
+        struct ChildProps {
+            name: String,
+            delay: u64,
+        }
+
+        #[component]
+        fn Child(props: &ChildProps) -> HtmlResult {
+            let delay = props.delay;
+            let state = use_state(|| {
+                let (s, handle) = Suspension::new();
+
+                spawn_local(async move {
+                    sleep(Duration::from_millis(delay)).await;
+
+                    handle.resume();
+                });
+
+                s
+            });
+
+            if !state.resumed() {
+                return Err((*state).clone().into());
+            }
+
+            Ok(html! { <div>{"Hello, "}{&props.name}{"!"}</div> })
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <SuspenseList>
+                    <Suspense fallback={html! {"loading Jane..."}}>
+                        <Child name="Jane" delay={50} />
+                    </Suspense>
+                    <Suspense fallback={html! {"loading John..."}}>
+                        <Child name="John" delay={10} />
+                    </Suspense>
+                </SuspenseList>
+            }
+        }
+
+        let local = LocalSet::new();
+
+        let s = local
+            .run_until(async move {
+                ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .out_of_order(true)
+                    .render()
+                    .await
+            })
+            .await;
+
+        assert_eq!(s, "<div>Hello, Jane!</div><div>Hello, John!</div>");
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_suspense_timeout() {
+        use crate::suspense::use_future;
+
+        #[derive(PartialEq, Properties, Debug)]
+        struct ChildProps {
+            name: String,
+            delay: u64,
+        }
+
+        #[component]
+        fn Child(props: &ChildProps) -> HtmlResult {
+            let delay = props.delay;
+            use_future(move || async move {
+                sleep(Duration::from_millis(delay)).await;
+            })?;
+
+            Ok(html! { <div>{"Hello, "}{&props.name}{"!"}</div> })
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! {"loading Jane..."}}>
+                    <Child name="Jane" delay={10} />
+                </Suspense>
+                <Suspense fallback={html! {"loading John..."}}>
+                    <Child name="John" delay={60_000} />
+                </Suspense>
+            }
+        }
+
+        for out_of_order in [false, true] {
+            let s = ServerRenderer::<Comp>::new()
+                .hydratable(false)
+                .out_of_order(out_of_order)
+                .timeout(Duration::from_millis(100))
+                .render()
+                .await;
+
+            assert!(s.contains("<div>Hello, Jane!</div>"), "{s}");
+            assert!(s.contains("loading John..."), "{s}");
+            assert!(!s.contains("Hello, John!"), "{s}");
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .timeout(Duration::from_millis(100))
+            .render()
+            .await;
+
+        assert!(
+            s.contains(
+                r#"<template id="yew-sb-t0"></template>loading John...<template id="yew-se-t0"></template>"#
+            ),
+            "{s}"
+        );
+
+        // Boundaries inside of a <style> tag cannot be marked, so their children are awaited.
+        #[component]
+        fn Style() -> Html {
+            html! {
+                <style>
+                    <Suspense fallback={html! {"loading..."}}>
+                        <Child name="Jane" delay={200} />
+                    </Suspense>
+                </style>
+            }
+        }
+
+        let s = ServerRenderer::<Style>::new()
+            .timeout(Duration::from_millis(100))
+            .render()
+            .await;
+
+        assert!(s.contains("Hello, Jane!"), "{s}");
+        assert!(!s.contains("loading..."), "{s}");
+    }
 }
@@ -0,0 +1,89 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::Cell;
+
+use common::{obtain_result, output_element};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static SIBLING_DESTROYED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn click(id: &str) {
+    gloo::utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+}
+
+fn fallback() -> Callback<CaughtError, Html> {
+    Callback::from(|e: CaughtError| {
+        let has_error = e.error().is_some();
+        let message = e.message().to_owned();
+        let onclick = move |_| e.reset();
+
+        html! {
+            <div id="result">{message}{" "}{has_error}</div>
+            <button id="reset" {onclick}>{"Reset"}</button>
+        }
+    })
+}
+
+#[wasm_bindgen_test]
+async fn error_boundary_catches_render_errors() {
+    #[component]
+    fn Parse() -> HtmlResult {
+        let input = use_state(|| "42");
+        let n: u32 = input.parse()?;
+
+        Ok(html! {
+            <div id="result">{n}</div>
+            <button id="fail" onclick={move |_| input.set("forty-two")}>{"Fail"}</button>
+        })
+    }
+
+    #[component]
+    fn Sibling() -> Html {
+        use_effect_with((), |_| move || SIBLING_DESTROYED.with(|m| m.set(true)));
+
+        html! { <span>{"sibling"}</span> }
+    }
+
+    #[component]
+    fn App() -> Html {
+        html! {
+            <ErrorBoundary fallback={fallback()}>
+                <Sibling />
+                <Parse />
+            </ErrorBoundary>
+        }
+    }
+
+    let app = yew::Renderer::<App>::with_root(output_element()).render();
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "42");
+
+    click("fail");
+    scheduler::flush().await;
+    // The error is kept, and the children are unmounted for the fallback.
+    assert_eq!(obtain_result(), "invalid digit found in string true");
+    assert!(SIBLING_DESTROYED.with(|m| m.get()));
+    assert!(!output_element().inner_html().contains("sibling"));
+
+    click("reset");
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "42");
+    assert!(output_element().inner_html().contains("sibling"));
+
+    app.destroy();
+}
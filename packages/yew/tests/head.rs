@@ -0,0 +1,87 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn descriptions() -> Vec<String> {
+    let tags = gloo::utils::document()
+        .head()
+        .unwrap()
+        .query_selector_all(r#"meta[name="description"]"#)
+        .unwrap();
+
+    (0..tags.length())
+        .map(|i| {
+            tags.get(i)
+                .unwrap()
+                .unchecked_into::<web_sys::Element>()
+                .get_attribute("content")
+                .unwrap()
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+async fn head_dedupes_tags_by_key() {
+    #[component]
+    fn Page() -> Html {
+        html! {
+            <Head>
+                <title>{"Page"}</title>
+                <meta name="description" content="page" />
+            </Head>
+        }
+    }
+
+    #[component]
+    fn App() -> Html {
+        let show_page = use_state(|| true);
+        let onclick = {
+            let show_page = show_page.clone();
+            Callback::from(move |_| show_page.set(!*show_page))
+        };
+
+        html! {
+            <Head>
+                <title>{"App"}</title>
+                <meta name="description" content="app" />
+            </Head>
+            <button {onclick}>{"Toggle"}</button>
+            <div id="result">{if *show_page { "page" } else { "app" }}</div>
+            if *show_page {
+                <Page />
+            }
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    sleep(Duration::from_millis(10)).await;
+
+    assert_eq!(obtain_result(), "page");
+    assert_eq!(gloo::utils::document().title(), "Page");
+    assert_eq!(descriptions(), vec!["page".to_string()]);
+
+    gloo::utils::document()
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>()
+        .click();
+    sleep(Duration::from_millis(10)).await;
+
+    // The tags of the app are restored once the page is unmounted.
+    assert_eq!(obtain_result(), "app");
+    assert_eq!(gloo::utils::document().title(), "App");
+    assert_eq!(descriptions(), vec!["app".to_string()]);
+}
@@ -428,6 +428,68 @@ async fn hydration_with_suspense_not_suspended_at_start() {
     );
 }
 
+#[wasm_bindgen_test]
+async fn hydration_with_suspense_out_of_order() {
+    #[component(Content)]
+    fn content() -> HtmlResult {
+        let s = use_future(|| async {
+            sleep(Duration::from_millis(50)).await;
+
+            "I am loaded!"
+        })?;
+
+        Ok(html! { <div class="content-area">{*s}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <div id="result">
+                <Suspense {fallback}>
+                    <Content />
+                </Suspense>
+                <div class="after">{"after"}</div>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .out_of_order(true)
+        .render()
+        .await;
+
+    // The fallback is part of the shell, the content is streamed after it.
+    let (shell, _) = s.split_once("<script>").unwrap();
+    assert!(shell.contains("<div>wait...</div>"));
+    assert!(!shell.contains("I am loaded!"));
+
+    // Scripts inserted with innerHTML are not executed, the content is swapped in by hydration.
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::from_millis(100)).await;
+
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<div class="content-area">I am loaded!</div><div class="after">after</div>"#
+    );
+    assert!(
+        !obtain_result_by_id("output").contains("<template"),
+        "all streamed templates should be removed"
+    );
+}
+
 #[wasm_bindgen_test]
 async fn hydration_nested_suspense_works() {
     #[derive(PartialEq)]
@@ -1260,3 +1322,321 @@ async fn hydration_suspended_child_does_not_trap_sibling_slot() {
         r#"<p class="new-sibling">new sibling</p><div class="suspended">child</div>"#,
     );
 }
+
+#[wasm_bindgen_test]
+async fn hydration_use_id_matches_server() {
+    thread_local! {
+        static IDS: std::cell::RefCell<Vec<AttrValue>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Field() -> Html {
+        let id = use_id();
+        // Recorded once per component, on the server and on the client.
+        use_state(|| IDS.with(|m| m.borrow_mut().push(id.clone())));
+
+        html! {
+            <label for={id}>{"Name"}</label>
+        }
+    }
+
+    #[component]
+    fn App() -> Html {
+        html! {
+            <div id="result">
+                <Field />
+                <Field />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+    let server_ids = IDS.with(|m| m.take());
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    scheduler::flush().await;
+
+    let client_ids = IDS.with(|m| m.take());
+    assert_eq!(server_ids.len(), 2);
+    assert_ne!(server_ids[0], server_ids[1]);
+    assert_eq!(client_ids, server_ids);
+    assert_eq!(
+        obtain_result(),
+        format!(
+            r#"<label for="{}">Name</label><label for="{}">Name</label>"#,
+            server_ids[0], server_ids[1]
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hydration_mismatch_renders_component_on_client() {
+    thread_local! {
+        static ON_CLIENT: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+        static MISMATCHES: std::cell::RefCell<Vec<yew::HydrationMismatch>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Inner() -> Html {
+        let ctr = use_state(|| 0);
+        let onclick = {
+            let ctr = ctr.clone();
+            Callback::from(move |_| ctr.set(*ctr + 1))
+        };
+
+        if ON_CLIENT.with(|m| m.get()) {
+            html! { <p {onclick} class="inner">{*ctr}</p> }
+        } else {
+            html! { <span>{"server"}</span> }
+        }
+    }
+
+    #[component]
+    fn App() -> Html {
+        html! {
+            <div id="result">
+                <div>{"before"}</div>
+                <Inner />
+                <div>{"after"}</div>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    ON_CLIENT.with(|m| m.set(true));
+    yew::set_hydration_mismatch_hook(|mismatch| {
+        MISMATCHES.with(|m| m.borrow_mut().push(mismatch.clone()))
+    });
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    scheduler::flush().await;
+
+    assert_eq!(
+        obtain_result(),
+        r#"<div>before</div><p class="inner">0</p><div>after</div>"#
+    );
+
+    let mismatches = MISMATCHES.with(|m| m.take());
+    assert_eq!(mismatches.len(), 1);
+    assert!(mismatches[0].component_path()[0].ends_with("App"));
+    assert!(mismatches[0].component_path()[1].ends_with("Inner"));
+    assert!(mismatches[0].component().ends_with("Inner"));
+    assert_eq!(mismatches[0].expected(), "p element node");
+    assert_eq!(mismatches[0].found(), "span element node");
+
+    // The component rendered on the client is interactive.
+    gloo::utils::document()
+        .query_selector(".inner")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    scheduler::flush().await;
+
+    assert_eq!(
+        obtain_result(),
+        r#"<div>before</div><p class="inner">1</p><div>after</div>"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hydration_mismatch_describes_text() {
+    thread_local! {
+        static ON_CLIENT: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+        static MISMATCHES: std::cell::RefCell<Vec<yew::HydrationMismatch>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Inner() -> Html {
+        if ON_CLIENT.with(|m| m.get()) {
+            html! { <p>{"client"}</p> }
+        } else {
+            html! { {"server"} }
+        }
+    }
+
+    #[component]
+    fn App() -> Html {
+        html! {
+            <div id="result">
+                <Inner />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    ON_CLIENT.with(|m| m.set(true));
+    yew::set_hydration_mismatch_hook(|mismatch| {
+        MISMATCHES.with(|m| m.borrow_mut().push(mismatch.clone()))
+    });
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "<p>client</p>");
+
+    let mismatches = MISMATCHES.with(|m| m.take());
+    assert_eq!(mismatches.len(), 1);
+    assert!(mismatches[0].component().ends_with("Inner"));
+    assert_eq!(mismatches[0].expected(), "p element node");
+    assert_eq!(mismatches[0].found(), r#"text node "server""#);
+}
+
+#[wasm_bindgen_test]
+async fn lazy_hydrate_waits_for_interaction() {
+    #[component]
+    fn Counter() -> Html {
+        let ctr = use_state(|| 0);
+        let onclick = {
+            let ctr = ctr.clone();
+            Callback::from(move |_| ctr.set(*ctr + 1))
+        };
+
+        html! {
+            <button {onclick} class="counter">{*ctr}</button>
+        }
+    }
+
+    #[component]
+    fn App() -> Html {
+        html! {
+            <div id="result">
+                <LazyHydrate when={HydrateWhen::Interaction("click".into())}>
+                    <Counter />
+                </LazyHydrate>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    scheduler::flush().await;
+
+    let click = || {
+        gloo::utils::document()
+            .query_selector(".counter")
+            .unwrap()
+            .unwrap()
+            .dyn_into::<HtmlElement>()
+            .unwrap()
+            .click()
+    };
+
+    // The server markup is kept, including the component markers of the children.
+    assert!(obtain_result().contains(r#"<button class="counter">0</button>"#));
+    assert_ne!(obtain_result(), r#"<button class="counter">0</button>"#);
+
+    // The first click hydrates the children, but is not handled by them.
+    click();
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), r#"<button class="counter">0</button>"#);
+
+    click();
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), r#"<button class="counter">1</button>"#);
+}
+
+#[wasm_bindgen_test]
+async fn islands_hydrate_independently() {
+    #[derive(Properties, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct CounterProps {
+        initial: u32,
+    }
+
+    #[component]
+    fn Counter(props: &CounterProps) -> Html {
+        let ctr = use_state(|| props.initial);
+        let onclick = {
+            let ctr = ctr.clone();
+            Callback::from(move |_| ctr.set(*ctr + 1))
+        };
+
+        html! {
+            <button {onclick} class={format!("counter-{}", props.initial)}>{*ctr}</button>
+        }
+    }
+
+    impl yew::islands::IslandComponent for Counter {
+        const NAME: &'static str = "counter";
+    }
+
+    #[component]
+    fn Page() -> Html {
+        html! {
+            <div id="result">
+                <p>{"static"}</p>
+                <yew::islands::Island<Counter> initial={1} />
+                <p>{"static"}</p>
+                <yew::islands::Island<Counter> initial={5} />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<Page>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    yew::islands::register::<Counter>();
+    yew::islands::hydrate_all();
+
+    // The properties are decoded asynchronously.
+    sleep(Duration::from_millis(50)).await;
+    scheduler::flush().await;
+
+    for class in ["counter-1", "counter-5"] {
+        gloo::utils::document()
+            .query_selector(&format!(".{class}"))
+            .unwrap()
+            .unwrap()
+            .dyn_into::<HtmlElement>()
+            .unwrap()
+            .click();
+    }
+
+    scheduler::flush().await;
+
+    let result = obtain_result();
+    assert!(result.contains(r#"<button class="counter-1">2</button>"#));
+    assert!(result.contains(r#"<button class="counter-5">6</button>"#));
+}
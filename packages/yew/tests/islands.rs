@@ -0,0 +1,94 @@
+#![cfg(feature = "hydration")]
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+use std::time::Duration;
+
+mod common;
+
+use common::{obtain_result_by_id, output_element};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::islands::{Island, IslandComponent};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::{Renderer, ServerRenderer, scheduler};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Serialize, Deserialize)]
+struct CounterProps {
+    id: String,
+    initial: u32,
+}
+
+#[component]
+fn Counter(props: &CounterProps) -> Html {
+    let ctr = use_state(|| props.initial);
+    let onclick = {
+        let ctr = ctr.clone();
+        move |_| ctr.set(*ctr + 1)
+    };
+
+    html! { <button id={props.id.clone()} {onclick}>{*ctr}</button> }
+}
+
+impl IslandComponent for Counter {
+    const NAME: &'static str = "counter";
+}
+
+#[component]
+fn Page() -> Html {
+    html! {
+        <article>
+            <Island<Counter> id="first" initial={1} />
+            <Island<Counter> id="second" initial={2} />
+        </article>
+    }
+}
+
+fn click(id: &str) {
+    gloo::utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+}
+
+#[wasm_bindgen_test]
+async fn islands_rendered_on_the_client_have_no_properties() {
+    output_element().set_inner_html("");
+    let app = Renderer::<Page>::with_root(output_element()).render();
+    scheduler::flush().await;
+
+    let result = obtain_result_by_id("output");
+    assert!(!result.contains("data-props"));
+    assert!(result.contains(r#"<button id="second">2</button>"#));
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn islands_with_malformed_properties_are_skipped() {
+    let s = ServerRenderer::<Page>::new().render().await;
+    output_element().set_inner_html(&s);
+    output_element()
+        .query_selector("yew-island")
+        .unwrap()
+        .unwrap()
+        .set_attribute("data-props", "%%%")
+        .unwrap();
+
+    yew::islands::register::<Counter>();
+    yew::islands::hydrate_all();
+    sleep(Duration::from_millis(50)).await;
+
+    click("first");
+    click("second");
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result_by_id("first"), "1");
+    assert_eq!(obtain_result_by_id("second"), "3");
+}
@@ -6,7 +6,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 use std::time::Duration;
 
-use common::obtain_result;
+use common::{obtain_result, obtain_result_by_id};
 use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
 use web_sys::{HtmlElement, HtmlTextAreaElement};
@@ -816,3 +816,186 @@ async fn test_duplicate_suspension() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "hello!");
 }
+
+#[wasm_bindgen_test]
+async fn suspense_list_reveals_forwards() {
+    use yew::suspense::{RevealOrder, SuspenseList, SuspenseListTail};
+
+    #[derive(PartialEq, Properties)]
+    struct ContentProps {
+        name: AttrValue,
+        delay_millis: u64,
+    }
+
+    #[component(Content)]
+    fn content(props: &ContentProps) -> HtmlResult {
+        use_future_with(props.delay_millis, |delay_millis| async move {
+            sleep(Duration::from_millis(*delay_millis)).await;
+        })?;
+
+        Ok(html! { <div>{props.name.clone()}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        html! {
+            <div id="result">
+                <SuspenseList reveal_order={RevealOrder::Forwards} tail={SuspenseListTail::Collapsed}>
+                    <Suspense fallback={html! {<div>{"wait 1..."}</div>}}>
+                        <Content name="1" delay_millis={100} />
+                    </Suspense>
+                    <Suspense fallback={html! {<div>{"wait 2..."}</div>}}>
+                        <Content name="2" delay_millis={30} />
+                    </Suspense>
+                </SuspenseList>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(obtain_result(), "<div>wait 1...</div>");
+
+    // The second boundary has resolved, but it is held back until the first one is revealed.
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(obtain_result(), "<div>wait 1...</div>");
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(obtain_result(), "<div>1</div><div>2</div>");
+}
+
+#[wasm_bindgen_test]
+async fn suspense_list_orders_inserted_boundaries() {
+    use yew::suspense::{RevealOrder, SuspenseList};
+
+    #[derive(PartialEq, Properties)]
+    struct ContentProps {
+        name: AttrValue,
+        delay_millis: u64,
+    }
+
+    #[component(Content)]
+    fn content(props: &ContentProps) -> HtmlResult {
+        use_future_with(props.delay_millis, |delay_millis| async move {
+            sleep(Duration::from_millis(*delay_millis)).await;
+        })?;
+
+        Ok(html! { <div>{props.name.clone()}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let show_second = use_state(|| false);
+        {
+            let show_second = show_second.clone();
+            use_effect_with((), move |_| show_second.set(true));
+        }
+
+        html! {
+            <div id="result">
+                <SuspenseList reveal_order={RevealOrder::Forwards}>
+                    <Suspense fallback={html! {<div>{"wait 1..."}</div>}}>
+                        <Content name="1" delay_millis={0} />
+                    </Suspense>
+                    if *show_second {
+                        <Suspense fallback={html! {<div>{"wait 2..."}</div>}}>
+                            <Content name="2" delay_millis={150} />
+                        </Suspense>
+                    }
+                    <Suspense fallback={html! {<div>{"wait 3..."}</div>}}>
+                        <Content name="3" delay_millis={50} />
+                    </Suspense>
+                </SuspenseList>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::from_millis(20)).await;
+    assert_eq!(
+        obtain_result(),
+        "<div>1</div><div>wait 2...</div><div>wait 3...</div>"
+    );
+
+    // The third boundary has resolved, but it is held back by the second one, which has been
+    // inserted before it.
+    sleep(Duration::from_millis(60)).await;
+    assert_eq!(
+        obtain_result(),
+        "<div>1</div><div>wait 2...</div><div>wait 3...</div>"
+    );
+
+    sleep(Duration::from_millis(150)).await;
+    assert_eq!(obtain_result(), "<div>1</div><div>2</div><div>3</div>");
+}
+
+#[wasm_bindgen_test]
+async fn transition_keeps_content_visible() {
+    use yew::suspense::use_transition;
+
+    #[derive(PartialEq, Properties)]
+    struct ContentProps {
+        page: u32,
+    }
+
+    #[component(Content)]
+    fn content(props: &ContentProps) -> HtmlResult {
+        let page = use_future_with(props.page, |page| async move {
+            sleep(Duration::from_millis(50)).await;
+            *page
+        })?;
+
+        Ok(html! { <div>{format!("page {}", *page)}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let page = use_state(|| 1);
+        let (is_pending, start_transition) = use_transition();
+
+        let onclick = {
+            let page = page.clone();
+            Callback::from(move |_| {
+                let page = page.clone();
+                start_transition.start(move || page.set(*page + 1));
+            })
+        };
+
+        html! {
+            <button {onclick}>{"Next"}</button>
+            <div id="pending">{is_pending}</div>
+            <div id="result">
+                <Suspense fallback={html! {<div>{"wait..."}</div>}}>
+                    <Content page={*page} />
+                </Suspense>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(obtain_result(), "<div>page 1</div>");
+    assert_eq!(obtain_result_by_id("pending"), "false");
+
+    gloo::utils::document()
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>()
+        .click();
+
+    // The previous page stays visible while the next one is loading.
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(obtain_result(), "<div>page 1</div>");
+    assert_eq!(obtain_result_by_id("pending"), "true");
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(obtain_result(), "<div>page 2</div>");
+    assert_eq!(obtain_result_by_id("pending"), "false");
+}
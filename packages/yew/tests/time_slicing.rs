@@ -0,0 +1,61 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Disables time-sliced rendering again, even if the test fails.
+struct FrameBudget;
+
+impl FrameBudget {
+    fn set(budget: Duration) -> Self {
+        scheduler::set_frame_budget(Some(budget));
+        Self
+    }
+}
+
+impl Drop for FrameBudget {
+    fn drop(&mut self) {
+        scheduler::set_frame_budget(None);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn time_sliced_rendering_keeps_order() {
+    #[derive(Properties, Clone, PartialEq)]
+    struct RowProps {
+        index: usize,
+    }
+
+    #[component]
+    fn Row(props: &RowProps) -> Html {
+        html! { <li>{props.index}</li> }
+    }
+
+    #[component]
+    fn List() -> Html {
+        html! {
+            <ul id="result">
+                { for (0..50).map(|index| html! { <Row key={index} {index} /> }) }
+            </ul>
+        }
+    }
+
+    // An empty budget yields to the browser after every rendered component.
+    let _budget = FrameBudget::set(Duration::ZERO);
+
+    yew::Renderer::<List>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    scheduler::flush().await;
+
+    let expected: String = (0..50).map(|i| format!("<li>{i}</li>")).collect();
+    assert_eq!(obtain_result(), expected);
+}
@@ -0,0 +1,44 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_deferred_value_lags_behind() {
+    thread_local! {
+        static RENDERS: RefCell<Vec<(u32, u32)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component(UseDeferredValueComponent)]
+    fn use_deferred_value_comp() -> Html {
+        let value = use_state(|| 0);
+        let deferred = use_deferred_value(*value);
+        RENDERS.with(|m| m.borrow_mut().push((*value, deferred)));
+
+        use_effect_with((), move |_| value.set(1));
+
+        html! {
+            <div id="result">{deferred}</div>
+        }
+    }
+
+    yew::Renderer::<UseDeferredValueComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    sleep(Duration::from_millis(50)).await;
+
+    // The urgent render still shows the previous value.
+    RENDERS.with(|m| assert_eq!(*m.borrow(), [(0, 0), (1, 0), (1, 1)]));
+    assert_eq!(obtain_result(), "1");
+}
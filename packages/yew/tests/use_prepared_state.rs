@@ -113,3 +113,56 @@ async fn use_prepared_state_with_suspension_works() {
     // no placeholders, hydration is successful and state 12345 is preserved.
     assert_eq!(result, r#"<div><div>12345</div></div>"#);
 }
+
+#[cfg(feature = "json")]
+#[wasm_bindgen_test]
+async fn use_prepared_state_json_codec_works() {
+    use yew::functional::StateCodec;
+
+    #[component]
+    fn Comp() -> HtmlResult {
+        let ctr = use_prepared_state!((), |_| -> u32 { 12345 })?.unwrap_or_default();
+
+        Ok(html! {
+            <div>
+                {*ctr}
+            </div>
+        })
+    }
+
+    #[component]
+    fn App() -> Html {
+        html! {
+            <Suspense fallback={Html::default()}>
+                <div>
+                    <Comp />
+                </div>
+            </Suspense>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .state_codec(StateCodec::Json)
+        .render()
+        .await;
+
+    assert!(s.contains(r#"<script type="application/x-yew-comp-state">[[12345,null]]</script>"#));
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .state_codec(StateCodec::Json)
+        .hydrate();
+
+    sleep(Duration::from_millis(200)).await;
+
+    let result = obtain_result_by_id("output");
+
+    assert_eq!(result, r#"<div><div>12345</div></div>"#);
+}
@@ -0,0 +1,88 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::{Cell, RefCell};
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_sync_external_store_rerenders_on_change() {
+    thread_local! {
+        static VALUE: Cell<u32> = const { Cell::new(0) };
+        static LISTENERS: RefCell<Vec<Callback<()>>> = const { RefCell::new(Vec::new()) };
+        static RENDERS: Cell<u32> = const { Cell::new(0) };
+    }
+
+    fn set_value(value: u32) {
+        VALUE.with(|m| m.set(value));
+        for listener in LISTENERS.with(|m| m.borrow().clone()) {
+            listener.emit(());
+        }
+    }
+
+    #[component(UseSyncExternalStoreComponent)]
+    fn use_sync_external_store_comp() -> Html {
+        let value = use_sync_external_store(
+            |on_change| LISTENERS.with(|m| m.borrow_mut().push(on_change)),
+            || VALUE.with(|m| m.get()),
+            || 0,
+        );
+        RENDERS.with(|m| m.set(m.get() + 1));
+
+        html! {
+            <div id="result">{value}</div>
+        }
+    }
+
+    yew::Renderer::<UseSyncExternalStoreComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "0");
+    assert_eq!(RENDERS.with(|m| m.get()), 1);
+
+    set_value(1);
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "1");
+    assert_eq!(RENDERS.with(|m| m.get()), 2);
+
+    // Notifications without a change of the snapshot do not re-render.
+    set_value(1);
+    scheduler::flush().await;
+    assert_eq!(RENDERS.with(|m| m.get()), 2);
+}
+
+#[cfg(feature = "ssr")]
+#[wasm_bindgen_test]
+async fn use_sync_external_store_uses_snapshot_on_client_render() {
+    thread_local! {
+        static RENDERS: Cell<u32> = const { Cell::new(0) };
+    }
+
+    // Components rendered on the client do not start with the server snapshot, even if the
+    // server renderer is enabled as well.
+    #[component(ClientComponent)]
+    fn client_comp() -> Html {
+        let value = use_sync_external_store(|_| {}, || "client", || "server");
+        RENDERS.with(|m| m.set(m.get() + 1));
+
+        html! {
+            <div id="result">{value}</div>
+        }
+    }
+
+    yew::Renderer::<ClientComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "client");
+    assert_eq!(RENDERS.with(|m| m.get()), 1);
+}